@@ -1,363 +1,777 @@
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, AttributeArgs, Data::{Enum, Struct}, DataEnum, DataStruct, DeriveInput, FieldsNamed};
+use syn::parse::Parser;
+use syn::punctuated::Punctuated;
+use syn::{parse_macro_input, Data::{Enum, Struct}, DataEnum, DataStruct, DeriveInput, Fields, FieldsNamed, Meta, Token, Variant};
 
-/// adds Transfer { to: Addr } variant to ExecuteMsg
+// syn 2 dropped AttributeArgs, attribute arguments are parsed as a comma separated list of Meta
+type AttributeArgs = Punctuated<Meta, Token![,]>;
+
+/// adds Transfer { to: Addr } variant to ExecuteMsg.
+/// #[ownables_transfer(memo)] adds a `memo: Option<String>` field
 #[proc_macro_attribute]
 pub fn ownables_transfer(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_transfer", transfer_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // validate no input args
-    let meta_ast = parse_macro_input!(metadata as AttributeArgs);
-    if let Some(arg) = meta_ast.first() {
-        return syn::Error::new_spanned(arg, "no args expected")
-            .to_compile_error()
-            .into();
-    }
-
-    // define the variants to be inserted and parse into DataEnum
-    let default_execute_variants: TokenStream = quote! {
-        enum ExecuteMsg {
-            Transfer {to: Addr},
-        }
-    }
-    .into();
-    let default_ast: DeriveInput = parse_macro_input!(default_execute_variants);
-    let default_variants = match default_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can provide variants"),
-    };
-
-    // parse the input variants
-    let mut input_ast: DeriveInput = parse_macro_input!(input);
-    let input_variants_data = match &mut input_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can accept variants")
-    };
+/// adds Lock {} variant to ExecuteMsg.
+/// #[ownables_lock(reason)] adds a `reason: Option<String>` field
+#[proc_macro_attribute]
+pub fn ownables_lock(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_lock", lock_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // insert variants from the default to input
-    input_variants_data.extend(default_variants.into_iter());
+/// adds Consume {} variant to ExecuteMsg.
+/// #[ownables_consume(amount)] adds an `amount: Uint128` field
+#[proc_macro_attribute]
+pub fn ownables_consume(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_consume", consume_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    quote! { #input_ast }.into()
+/// adds GetMetadata {} variant to QueryMsg
+#[proc_macro_attribute]
+pub fn ownables_query_metadata(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_query_metadata", metadata_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-/// adds Lock {} variant to ExecuteMsg
+/// adds GetInfo {} variant to QueryMsg
 #[proc_macro_attribute]
-pub fn ownables_lock(metadata: TokenStream, input: TokenStream) -> TokenStream {
+pub fn ownables_query_info(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_query_info", info_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // validate no input args
-    let meta_ast = parse_macro_input!(metadata as AttributeArgs);
-    if let Some(arg) = meta_ast.first() {
-        return syn::Error::new_spanned(arg, "no args expected")
-            .to_compile_error()
-            .into();
-    }
+/// adds GetWidgetState {} variant to QueryMsg
+#[proc_macro_attribute]
+pub fn ownables_query_widget_state(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_query_widget_state", widget_state_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // define the variants to be inserted and parse into DataEnum
-    let default_execute_variants: TokenStream = quote! {
-        enum ExecuteMsg {
-            Lock {},
-        }
-    }
-        .into();
-    let default_ast: DeriveInput = parse_macro_input!(default_execute_variants);
-    let default_variants = match default_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can provide variants"),
-    };
+/// adds IsLocked {} variant to QueryMsg
+#[proc_macro_attribute]
+pub fn ownables_query_locked(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_query_locked", locked_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // parse the input variants
-    let mut input_ast: DeriveInput = parse_macro_input!(input);
-    let input_variants_data = match &mut input_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can accept variants")
-    };
+/// adds GetBalance {} variant to QueryMsg
+#[proc_macro_attribute]
+pub fn ownables_query_balance(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_query_balance", balance_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // insert variants from the default to input
-    input_variants_data.extend(default_variants.into_iter());
+/// adds RoyaltyInfo { sale_price: Uint128 } variant to QueryMsg
+#[proc_macro_attribute]
+pub fn ownables_query_royalty_info(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_query_royalty_info", royalty_info_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    quote! { #input_ast }.into()
+/// adds IsConsumerOf { issuer: Addr, consumable_type: String, } variant to QueryMsg
+#[proc_macro_attribute]
+pub fn ownables_query_consumer_of(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capability(meta_ast, input_ast, "ownables_query_consumer_of", consumer_of_capability())
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
 }
 
-/// adds Consume {} variant to ExecuteMsg
+/// adds the default fields to InstantiateMsg struct:
+/// InstantiateMsg {
+///     pub ownable_id: String,
+///     pub package: String,
+///     pub nft: Option<NFT>,
+///     pub ownable_type: Option<String>,
+///     pub network_id: u8,
+/// }
+/// and implements ownable_std::OwnableInstantiate for it, which provides
+/// accessors for these fields and validate(). NFT is the type in scope,
+/// which needs to implement ownable_std::instantiate::NftInfo
 #[proc_macro_attribute]
-pub fn ownables_consume(metadata: TokenStream, input: TokenStream) -> TokenStream {
+pub fn ownables_instantiate_msg(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_instantiate_msg(meta_ast, input_ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // validate no input args
-    let meta_ast = parse_macro_input!(metadata as AttributeArgs);
-    if let Some(arg) = meta_ast.first() {
-        return syn::Error::new_spanned(arg, "no args expected")
-            .to_compile_error()
-            .into();
-    }
+fn expand_instantiate_msg(
+    args: AttributeArgs,
+    mut input_ast: DeriveInput,
+) -> syn::Result<proc_macro2::TokenStream> {
+    expect_no_args(&args)?;
 
-    // define the variants to be inserted and parse into DataEnum
-    let default_execute_variants: TokenStream = quote! {
-        enum ExecuteMsg {
-            Consume {},
+    // define the fields to be inserted
+    let default_fields: FieldsNamed = syn::parse2(quote! {
+        {
+            pub ownable_id: String,
+            pub package: String,
+            pub nft: Option<NFT>,
+            pub ownable_type: Option<String>,
+            pub network_id: u8,
+        }
+    })?;
+
+    // only structs with named fields can accept fields
+    let input_fields = match &mut input_ast.data {
+        Struct(DataStruct { fields: Fields::Named(FieldsNamed { named, .. }), .. }) => named,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input_ast.ident,
+                "ownables_instantiate_msg can only be applied to a struct with named fields",
+            ))
         }
-    }
-        .into();
-    let default_ast: DeriveInput = parse_macro_input!(default_execute_variants);
-    let default_variants = match default_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can provide variants"),
     };
 
-    // parse the input variants
-    let mut input_ast: DeriveInput = parse_macro_input!(input);
-    let input_variants_data = match &mut input_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can accept variants")
-    };
+    // push the default fields onto the input
+    for field in default_fields.named {
+        if let Some(existing) = input_fields.iter().find(|f| f.ident == field.ident) {
+            return Err(syn::Error::new_spanned(
+                &existing.ident,
+                format!("field `{}` is added by ownables_instantiate_msg", existing.ident.as_ref().unwrap()),
+            ));
+        }
+        input_fields.push(field);
+    }
 
-    // insert variants from the default to input
-    input_variants_data.extend(default_variants.into_iter());
+    let ident = &input_ast.ident;
+    let (impl_generics, ty_generics, where_clause) = input_ast.generics.split_for_impl();
 
-    quote! { #input_ast }.into()
-}
+    Ok(quote! {
+        #input_ast
 
+        impl #impl_generics ::ownable_std::OwnableInstantiate for #ident #ty_generics #where_clause {
+            type Nft = NFT;
 
-/// adds GetMetadata {} variant to QueryMsg
-#[proc_macro_attribute]
-pub fn ownables_query_metadata(metadata: TokenStream, input: TokenStream) -> TokenStream {
-   
-    // validate no input args
-    let meta_ast = parse_macro_input!(metadata as AttributeArgs);
-    if let Some(arg) = meta_ast.first() {
-        return syn::Error::new_spanned(arg, "no args expected")
-            .to_compile_error()
-            .into();
-    }
+            fn ownable_id(&self) -> &str {
+                &self.ownable_id
+            }
 
-    // define the variants to be inserted and parse into DataEnum
-    let default_query_variants: TokenStream = quote! {
-        enum QueryMsg {
-            GetMetadata {},
-        }
-    }
-    .into();
-    let default_ast: DeriveInput = parse_macro_input!(default_query_variants);
-    let default_variants = match default_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can provide variants"),
-    };
+            fn package(&self) -> &str {
+                &self.package
+            }
 
-    // parse the input variants
-    let mut input_ast: DeriveInput = parse_macro_input!(input);
-    let input_variants_data = match &mut input_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can accept variants")
-    };
+            fn nft(&self) -> ::core::option::Option<&Self::Nft> {
+                self.nft.as_ref()
+            }
 
-    // insert variants from the default to input
-    input_variants_data.extend(default_variants.into_iter());
+            fn ownable_type(&self) -> ::core::option::Option<&str> {
+                self.ownable_type.as_deref()
+            }
 
-    quote! { #input_ast }.into()
+            fn network_id(&self) -> u8 {
+                self.network_id
+            }
+        }
+    })
 }
 
-/// adds GetInfo {} variant to QueryMsg
+/// adds the variants of the listed capabilities to ExecuteMsg:
+/// transfer => Transfer { to: Addr }
+/// lock => Lock {}
+/// consume => Consume {}
+/// approve => Approve { spender: Addr, expires: Option<Timestamp> }
+/// revoke => Revoke { spender: Addr }
+/// approve_all => ApproveAll { operator: Addr, expires: Option<Timestamp> }
+/// revoke_all => RevokeAll { operator: Addr }
+/// e.g. #[ownable(transfer, lock)]. the optional fields of the ownables_*
+/// macros are enabled the same way, e.g. #[ownable(transfer(memo))].
+/// the enabled capabilities are available
+/// as ExecuteMsg::OWNABLE_CAPABILITIES
 #[proc_macro_attribute]
-pub fn ownables_query_info(metadata: TokenStream, input: TokenStream) -> TokenStream {
+pub fn ownable(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capabilities(meta_ast, input_ast, "ownable", execute_capability)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// adds the variants of the listed capabilities to QueryMsg:
+/// info => GetInfo {}
+/// metadata => GetMetadata {}
+/// locked => IsLocked {}
+/// widget_state => GetWidgetState {}
+/// balance => GetBalance {}
+/// royalty_info => RoyaltyInfo { sale_price: Uint128 }
+/// approvals => GetApprovals {}
+/// consumer_of => IsConsumerOf { issuer: Addr, consumable_type: String }
+/// e.g. #[ownable_query(info, metadata)]. the enabled capabilities are
+/// available as QueryMsg::OWNABLE_CAPABILITIES
+#[proc_macro_attribute]
+pub fn ownable_query(metadata: TokenStream, input: TokenStream) -> TokenStream {
+    let meta_ast = parse_macro_input!(metadata with AttributeArgs::parse_terminated);
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_capabilities(meta_ast, input_ast, "ownable_query", query_capability)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // validate no input args
-    let meta_ast = parse_macro_input!(metadata as AttributeArgs);
-    if let Some(arg) = meta_ast.first() {
-        return syn::Error::new_spanned(arg, "no args expected")
-            .to_compile_error()
-            .into();
+/// a variant added by one of the macros. query variants have the type of
+/// their response, which is added as #[returns(...)] for cosmwasm-schema.
+/// options are the optional fields that can be enabled with macro args,
+/// e.g. #[ownables_transfer(memo)]
+struct Capability {
+    variant: proc_macro2::TokenStream,
+    returns: Option<proc_macro2::TokenStream>,
+    options: Vec<(&'static str, proc_macro2::TokenStream)>,
+}
+
+impl Capability {
+    fn execute(variant: proc_macro2::TokenStream) -> Capability {
+        Capability { variant, returns: None, options: vec![] }
     }
 
-    // define the variants to be inserted and parse into DataEnum
-    let default_query_variants: TokenStream = quote! {
-        enum QueryMsg {
-            GetInfo {},
-        }
+    fn query(variant: proc_macro2::TokenStream, returns: proc_macro2::TokenStream) -> Capability {
+        Capability { variant, returns: Some(returns), options: vec![] }
     }
-        .into();
-    let default_ast: DeriveInput = parse_macro_input!(default_query_variants);
-    let default_variants = match default_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can provide variants"),
-    };
 
-    // parse the input variants
-    let mut input_ast: DeriveInput = parse_macro_input!(input);
-    let input_variants_data = match &mut input_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can accept variants")
-    };
+    fn option(mut self, name: &'static str, field: proc_macro2::TokenStream) -> Capability {
+        self.options.push((name, field));
+        self
+    }
+
+    /// parses the variant, adding the fields of the options listed in args
+    fn into_variant(self, name: &str, args: &[syn::Ident]) -> syn::Result<(Variant, Option<proc_macro2::TokenStream>)> {
+        let mut variant: Variant = syn::parse2(self.variant)?;
+        let mut enabled: Vec<&syn::Ident> = vec![];
+
+        for arg in args {
+            if enabled.contains(&arg) {
+                return Err(syn::Error::new_spanned(arg, format!("duplicate argument `{}`", arg)));
+            }
+            let Some((_, field)) = self.options.iter().find(|(option, _)| arg == option) else {
+                let message = match self.options.is_empty() {
+                    true => format!("{} takes no arguments", name),
+                    false => format!(
+                        "unknown argument `{}`, {} accepts: {}",
+                        arg,
+                        name,
+                        self.options.iter().map(|(option, _)| *option).collect::<Vec<_>>().join(", ")
+                    ),
+                };
+                return Err(syn::Error::new_spanned(arg, message));
+            };
+            if let Fields::Named(FieldsNamed { named, .. }) = &mut variant.fields {
+                named.push(syn::Field::parse_named.parse2(field.clone())?);
+            }
+            enabled.push(arg);
+        }
+        Ok((variant, self.returns))
+    }
+}
 
-    // insert variants from the default to input
-    input_variants_data.extend(default_variants.into_iter());
+fn execute_capability(name: &str) -> Option<Capability> {
+    match name {
+        "transfer" => Some(transfer_capability()),
+        "lock" => Some(lock_capability()),
+        "consume" => Some(consume_capability()),
+        "approve" => Some(approve_capability()),
+        "revoke" => Some(revoke_capability()),
+        "approve_all" => Some(approve_all_capability()),
+        "revoke_all" => Some(revoke_all_capability()),
+        _ => None,
+    }
+}
 
-    quote! { #input_ast }.into()
+fn transfer_capability() -> Capability {
+    Capability::execute(quote! {
+        /// transfers the ownable to another address. only the owner and
+        /// approved addresses can transfer, and not while the ownable
+        /// is locked
+        Transfer { to: Addr }
+    })
+    .option("memo", quote! {
+        /// note added to the transfer
+        memo: Option<String>
+    })
 }
 
-/// adds GetWidgetState {} variant to QueryMsg
-#[proc_macro_attribute]
-pub fn ownables_query_widget_state(metadata: TokenStream, input: TokenStream) -> TokenStream {
+fn lock_capability() -> Capability {
+    Capability::execute(quote! {
+        /// locks the ownable, e.g. before it's bridged. a locked ownable
+        /// can't be transferred or consumed
+        Lock {}
+    })
+    .option("reason", quote! {
+        /// why the ownable is locked
+        reason: Option<String>
+    })
+}
 
-    // validate no input args
-    let meta_ast = parse_macro_input!(metadata as AttributeArgs);
-    if let Some(arg) = meta_ast.first() {
-        return syn::Error::new_spanned(arg, "no args expected")
-            .to_compile_error()
-            .into();
-    }
+fn consume_capability() -> Capability {
+    Capability::execute(quote! {
+        /// consumes the ownable, emitting a consume event for the consumer
+        Consume {}
+    })
+    .option("amount", quote! {
+        /// number of units to consume
+        amount: Uint128
+    })
+}
 
-    // define the variants to be inserted and parse into DataEnum
-    let default_query_variants: TokenStream = quote! {
-        enum QueryMsg {
-            GetWidgetState {},
+fn approve_capability() -> Capability {
+    Capability::execute(quote! {
+        /// approves a spender to transfer the ownable, until the block
+        /// time reaches `expires`. approvals are cleared on transfer
+        Approve {
+            spender: Addr,
+            expires: Option<Timestamp>,
         }
-    }
-        .into();
-    let default_ast: DeriveInput = parse_macro_input!(default_query_variants);
-    let default_variants = match default_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can provide variants"),
-    };
-
-    // parse the input variants
-    let mut input_ast: DeriveInput = parse_macro_input!(input);
-    let input_variants_data = match &mut input_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can accept variants")
-    };
+    })
+}
 
-    // insert variants from the default to input
-    input_variants_data.extend(default_variants.into_iter());
+fn revoke_capability() -> Capability {
+    Capability::execute(quote! {
+        /// revokes the approval of a spender
+        Revoke { spender: Addr }
+    })
+}
 
-    quote! { #input_ast }.into()
+fn approve_all_capability() -> Capability {
+    Capability::execute(quote! {
+        /// approves an operator to transfer the ownable and approve
+        /// spenders, until the block time reaches `expires`. only the
+        /// owner can approve operators
+        ApproveAll {
+            operator: Addr,
+            expires: Option<Timestamp>,
+        }
+    })
 }
 
-/// adds IsLocked {} variant to QueryMsg
-#[proc_macro_attribute]
-pub fn ownables_query_locked(metadata: TokenStream, input: TokenStream) -> TokenStream {
+fn revoke_all_capability() -> Capability {
+    Capability::execute(quote! {
+        /// revokes an operator. only the owner can revoke operators
+        RevokeAll { operator: Addr }
+    })
+}
 
-    // validate no input args
-    let meta_ast = parse_macro_input!(metadata as AttributeArgs);
-    if let Some(arg) = meta_ast.first() {
-        return syn::Error::new_spanned(arg, "no args expected")
-            .to_compile_error()
-            .into();
+fn query_capability(name: &str) -> Option<Capability> {
+    match name {
+        "info" => Some(info_capability()),
+        "metadata" => Some(metadata_capability()),
+        "locked" => Some(locked_capability()),
+        "widget_state" => Some(widget_state_capability()),
+        "balance" => Some(balance_capability()),
+        "royalty_info" => Some(royalty_info_capability()),
+        "approvals" => Some(approvals_capability()),
+        "consumer_of" => Some(consumer_of_capability()),
+        _ => None,
     }
+}
 
-    // define the variants to be inserted and parse into DataEnum
-    let default_query_variants: TokenStream = quote! {
-        enum QueryMsg {
-            IsLocked {},
-        }
-    }
-        .into();
-    let default_ast: DeriveInput = parse_macro_input!(default_query_variants);
-    let default_variants = match default_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can provide variants"),
-    };
+fn info_capability() -> Capability {
+    Capability::query(
+        quote! {
+            /// owner, issuer, nft and ownable type of the ownable
+            GetInfo {}
+        },
+        quote!(::ownable_std::InfoResponse),
+    )
+}
 
-    // parse the input variants
-    let mut input_ast: DeriveInput = parse_macro_input!(input);
-    let input_variants_data = match &mut input_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can accept variants")
-    };
+fn metadata_capability() -> Capability {
+    Capability::query(
+        quote! {
+            /// ERC-721 style metadata of the ownable
+            GetMetadata {}
+        },
+        quote!(::ownable_std::Metadata),
+    )
+}
 
-    // insert variants from the default to input
-    input_variants_data.extend(default_variants.into_iter());
+fn locked_capability() -> Capability {
+    Capability::query(
+        quote! {
+            /// whether the ownable is locked
+            IsLocked {}
+        },
+        quote!(::ownable_std::lock::IsLockedResponse),
+    )
+}
 
-    quote! { #input_ast }.into()
+fn widget_state_capability() -> Capability {
+    Capability::query(
+        quote! {
+            /// state used by the widget to render the ownable
+            GetWidgetState {}
+        },
+        quote!(::ownable_std::widget::WidgetState),
+    )
 }
 
-/// adds IsConsumerOf { issuer: Addr, consumable_type: String, } variant to QueryMsg
-#[proc_macro_attribute]
-pub fn ownables_query_consumer_of(metadata: TokenStream, input: TokenStream) -> TokenStream {
+fn balance_capability() -> Capability {
+    Capability::query(
+        quote! {
+            /// units left to consume
+            GetBalance {}
+        },
+        quote!(::ownable_std::consumable::BalanceResponse),
+    )
+}
 
-    // validate no input args
-    let meta_ast = parse_macro_input!(metadata as AttributeArgs);
-    if let Some(arg) = meta_ast.first() {
-        return syn::Error::new_spanned(arg, "no args expected")
-            .to_compile_error()
-            .into();
-    }
+fn royalty_info_capability() -> Capability {
+    Capability::query(
+        quote! {
+            /// creator fee for selling the ownable at the given price
+            RoyaltyInfo { sale_price: Uint128 }
+        },
+        quote!(::ownable_std::royalty::RoyaltyInfoResponse),
+    )
+}
+
+fn approvals_capability() -> Capability {
+    Capability::query(
+        quote! {
+            /// spenders and operators approved to transfer the ownable
+            GetApprovals {}
+        },
+        quote!(::ownable_std::approval::ApprovalsResponse),
+    )
+}
 
-    // define the variants to be inserted and parse into DataEnum
-    let default_query_variants: TokenStream = quote! {
-        enum QueryMsg {
+fn consumer_of_capability() -> Capability {
+    Capability::query(
+        quote! {
+            /// whether the ownable can consume ownables of the given
+            /// issuer and type
             IsConsumerOf {
                 issuer: Addr,
                 consumable_type: String,
-            },
+            }
+        },
+        quote!(::ownable_std::consumer::IsConsumerOfResponse),
+    )
+}
+
+/// inserts the variants of the capabilities listed in the macro arguments
+/// and records the capabilities in an OWNABLE_CAPABILITIES constant.
+/// a capability can have arguments of its own, e.g. transfer(memo)
+fn expand_capabilities(
+    args: AttributeArgs,
+    mut input_ast: DeriveInput,
+    macro_name: &str,
+    capability: fn(&str) -> Option<Capability>,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let mut names: Vec<syn::Ident> = vec![];
+    let mut variants = vec![];
+
+    for arg in args {
+        let (ident, capability_args) = match &arg {
+            Meta::Path(path) => (path.get_ident().cloned(), vec![]),
+            Meta::List(list) => (
+                list.path.get_ident().cloned(),
+                list.parse_args_with(Punctuated::<syn::Ident, Token![,]>::parse_terminated)?
+                    .into_iter()
+                    .collect(),
+            ),
+            _ => (None, vec![]),
+        };
+        let ident = ident.ok_or_else(|| syn::Error::new_spanned(&arg, "expected a capability name"))?;
+
+        if names.contains(&ident) {
+            return Err(syn::Error::new_spanned(
+                &ident,
+                format!("duplicate capability `{}`", ident),
+            ));
         }
+        let capability = capability(&ident.to_string()).ok_or_else(|| {
+            syn::Error::new_spanned(&ident, format!("unknown {} capability `{}`", macro_name, ident))
+        })?;
+        variants.push(capability.into_variant(&ident.to_string(), &capability_args)?);
+        names.push(ident);
     }
-        .into();
-    let default_ast: DeriveInput = parse_macro_input!(default_query_variants);
-    let default_variants = match default_ast.data {
-        Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can provide variants"),
-    };
 
-    // parse the input variants
-    let mut input_ast: DeriveInput = parse_macro_input!(input);
-    let input_variants_data = match &mut input_ast.data {
+    insert_variants(&mut input_ast, macro_name, variants)?;
+
+    let ident = &input_ast.ident;
+    let (impl_generics, ty_generics, where_clause) = input_ast.generics.split_for_impl();
+    let capabilities = names.iter().map(|name| name.to_string());
+
+    Ok(quote! {
+        #input_ast
+
+        impl #impl_generics #ident #ty_generics #where_clause {
+            pub const OWNABLE_CAPABILITIES: &'static [&'static str] = &[#(#capabilities),*];
+        }
+    })
+}
+
+/// inserts the variant of a single capability, for the ownables_* macros
+fn expand_capability(
+    args: AttributeArgs,
+    mut input_ast: DeriveInput,
+    macro_name: &str,
+    capability: Capability,
+) -> syn::Result<proc_macro2::TokenStream> {
+    let args = args
+        .iter()
+        .map(|arg| match arg {
+            Meta::Path(path) => path.get_ident().cloned(),
+            _ => None,
+        }
+        .ok_or_else(|| syn::Error::new_spanned(arg, "expected an argument name")))
+        .collect::<syn::Result<Vec<_>>>()?;
+    let variant = capability.into_variant(macro_name, &args)?;
+    insert_variants(&mut input_ast, macro_name, vec![variant])?;
+    Ok(quote! { #input_ast })
+}
+
+fn expect_no_args(args: &AttributeArgs) -> syn::Result<()> {
+    match args.first() {
+        Some(arg) => Err(syn::Error::new_spanned(arg, "no args expected")),
+        None => Ok(()),
+    }
+}
+
+/// appends variants to the input enum. errors if the input isn't an enum,
+/// or already has a variant with the same name
+fn insert_variants(
+    input_ast: &mut DeriveInput,
+    macro_name: &str,
+    variants: Vec<(Variant, Option<proc_macro2::TokenStream>)>,
+) -> syn::Result<()> {
+    let query_responses = derives_query_responses(input_ast);
+    let input_variants = match &mut input_ast.data {
         Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("only enums can accept variants")
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input_ast.ident,
+                format!("{} can only be applied to an enum", macro_name),
+            ))
+        }
     };
 
-    // insert variants from the default to input
-    input_variants_data.extend(default_variants.into_iter());
+    for (mut variant, returns) in variants {
+        if let Some(returns) = returns.filter(|_| query_responses) {
+            variant.attrs.push(syn::parse_quote!(#[returns(#returns)]));
+        }
+        if let Some(existing) = input_variants.iter().find(|v| v.ident == variant.ident) {
+            return Err(syn::Error::new_spanned(
+                &existing.ident,
+                format!("variant `{}` is added by {}", existing.ident, macro_name),
+            ));
+        }
+        input_variants.push(variant);
+    }
+    Ok(())
+}
 
-    quote! { #input_ast }.into()
+/// whether #[returns(...)] should be added to query variants, because the
+/// enum derives cosmwasm-schema's QueryResponses. like any derive, it must
+/// be placed below the ownables macros
+fn derives_query_responses(input_ast: &DeriveInput) -> bool {
+    input_ast.attrs.iter().filter(|attr| attr.path().is_ident("derive")).any(|attr| {
+        attr.parse_args_with(Punctuated::<syn::Path, Token![,]>::parse_terminated)
+            .map(|paths| paths.iter().any(|path| path.segments.last().is_some_and(|s| s.ident == "QueryResponses")))
+            .unwrap_or(false)
+    })
 }
 
-/// adds the default fields to InstantiateMsg struct:
-/// InstantiateMsg {
-///     pub ownable_id: String,
-///     pub package: String,
-///     pub nft: Option<NFT>,
-///     pub ownable_type: Option<String>,
-///     pub network_id: u8,
+/// generates `dispatch_ownable` for ExecuteMsg, which handles the Transfer,
+/// Lock, Consume, Approve, Revoke, ApproveAll and RevokeAll variants added
+/// by the ownables macros with the ownable_std implementations. the other
+/// variants are returned as Dispatch::Custom:
+/// match msg.dispatch_ownable(&mut deps, &env, &info) {
+///     Dispatch::Handled(result) => Ok(result?),
+///     Dispatch::Custom(msg) => match msg { ... },
 /// }
-#[proc_macro_attribute]
-pub fn ownables_instantiate_msg(metadata: TokenStream, input: TokenStream) -> TokenStream {
-    // validate no input args
-    let meta_ast = parse_macro_input!(metadata as AttributeArgs);
-    if let Some(arg) = meta_ast.first() {
-        return syn::Error::new_spanned(arg, "no args expected")
-            .to_compile_error()
-            .into();
-    }
+/// must be placed below the ownables macros
+#[proc_macro_derive(OwnableExecute)]
+pub fn derive_ownable_execute(input: TokenStream) -> TokenStream {
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_ownable_execute(input_ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
 
-    // define the fields to be inserted and parse into DataEnum
-    let default_instantiate_fields: TokenStream = quote! {
-        struct InstantiateMsg {
-            pub ownable_id: String,
-            pub package: String,
-            pub nft: Option<NFT>,
-            pub ownable_type: Option<String>,
-            pub network_id: u8,
+/// generates `dispatch_ownable` for QueryMsg, which handles the GetInfo,
+/// IsLocked, GetBalance, RoyaltyInfo, GetApprovals and IsConsumerOf
+/// variants added by the ownables macros with the ownable_std
+/// implementations. the other variants are returned as Dispatch::Custom.
+/// must be placed below the ownables macros
+#[proc_macro_derive(OwnableQuery)]
+pub fn derive_ownable_query(input: TokenStream) -> TokenStream {
+    let input_ast: DeriveInput = parse_macro_input!(input);
+    expand_ownable_query(input_ast)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+fn expand_ownable_execute(input_ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let arms = dispatch_arms(&input_ast, "OwnableExecute", |name, fields| {
+        let handler = match (name, fields) {
+            ("Transfer", ["to"]) => quote! {
+                ::ownable_std::ownership::try_transfer(deps.branch(), env.clone(), info.clone(), to)
+            },
+            ("Transfer", ["to", "memo"]) => quote! {
+                ::ownable_std::ownership::try_transfer_with_memo(deps.branch(), env.clone(), info.clone(), to, memo)
+            },
+            ("Lock", []) => quote! { ::ownable_std::lock::try_lock(deps.branch(), info.clone()) },
+            ("Lock", ["reason"]) => quote! {
+                ::ownable_std::lock::try_lock_with_reason(deps.branch(), info.clone(), reason)
+            },
+            ("Consume", []) => quote! {
+                ::ownable_std::consumable::try_consume(deps.branch(), env.clone(), info.clone())
+            },
+            ("Consume", ["amount"]) => quote! {
+                ::ownable_std::consumable::try_consume_amount(deps.branch(), env.clone(), info.clone(), amount)
+            },
+            ("Approve", ["spender", "expires"]) => quote! {
+                ::ownable_std::approval::try_approve(deps.branch(), env.clone(), info.clone(), spender, expires)
+            },
+            ("Revoke", ["spender"]) => quote! {
+                ::ownable_std::approval::try_revoke(deps.branch(), env.clone(), info.clone(), spender)
+            },
+            ("ApproveAll", ["operator", "expires"]) => quote! {
+                ::ownable_std::approval::try_approve_all(deps.branch(), env.clone(), info.clone(), operator, expires)
+            },
+            ("RevokeAll", ["operator"]) => quote! {
+                ::ownable_std::approval::try_revoke_all(deps.branch(), info.clone(), operator)
+            },
+            _ => return None,
+        };
+        Some(handler)
+    })?;
+
+    let ident = &input_ast.ident;
+    let (impl_generics, ty_generics, where_clause) = input_ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// handles the variants added by the ownables macros, and hands
+            /// back the other variants
+            #[allow(unreachable_patterns)]
+            pub fn dispatch_ownable(
+                self,
+                deps: &mut ::cosmwasm_std::DepsMut,
+                env: &::cosmwasm_std::Env,
+                info: &::cosmwasm_std::MessageInfo,
+            ) -> ::ownable_std::Dispatch<
+                ::core::result::Result<::cosmwasm_std::Response, ::ownable_std::OwnableError>,
+                Self,
+            > {
+                match self {
+                    #(#arms)*
+                    msg => ::ownable_std::Dispatch::Custom(msg),
+                }
+            }
         }
-    }
-    .into();
+    })
+}
 
-    let default_ast: DeriveInput = parse_macro_input!(default_instantiate_fields);
-    let default_fields = match default_ast.data {
-        Struct(DataStruct { fields, .. }) => fields,
-        _ => panic!("only structs can accept fields"),
-    };
+fn expand_ownable_query(input_ast: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let arms = dispatch_arms(&input_ast, "OwnableQuery", |name, fields| {
+        let handler = match (name, fields) {
+            ("GetInfo", []) => quote! { ::ownable_std::ownership::query_info(deps) },
+            ("IsLocked", []) => quote! { ::ownable_std::lock::query_locked(deps) },
+            ("GetBalance", []) => quote! { ::ownable_std::consumable::query_balance(deps) },
+            ("RoyaltyInfo", ["sale_price"]) => quote! {
+                ::ownable_std::royalty::query_royalty_info(deps, sale_price)
+            },
+            ("GetApprovals", []) => quote! { ::ownable_std::approval::query_approvals(deps, env.clone()) },
+            ("IsConsumerOf", ["issuer", "consumable_type"]) => quote! {
+                ::ownable_std::consumer::query_consumer_of(deps, issuer, consumable_type)
+            },
+            _ => return None,
+        };
+        Some(quote! { ::ownable_std::dispatch::to_query_binary(#handler) })
+    })?;
+
+    let ident = &input_ast.ident;
+    let (impl_generics, ty_generics, where_clause) = input_ast.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics #ident #ty_generics #where_clause {
+            /// handles the variants added by the ownables macros, and hands
+            /// back the other variants
+            #[allow(unreachable_patterns)]
+            pub fn dispatch_ownable(
+                self,
+                deps: ::cosmwasm_std::Deps,
+                env: &::cosmwasm_std::Env,
+            ) -> ::ownable_std::Dispatch<::cosmwasm_std::StdResult<::cosmwasm_std::Binary>, Self> {
+                match self {
+                    #(#arms)*
+                    msg => ::ownable_std::Dispatch::Custom(msg),
+                }
+            }
+        }
+    })
+}
 
-    let mut input_ast: DeriveInput = parse_macro_input!(input);
-    let input_fields_data = match &mut input_ast.data {
-        Struct(DataStruct { fields, .. }) => fields,
-        _ => panic!("only structs can accept fields")
+/// match arms for the variants that `handler` recognizes by their name and
+/// fields. variants with the name of an ownables variant, but other fields,
+/// are left to the contract
+fn dispatch_arms(
+    input_ast: &DeriveInput,
+    derive_name: &str,
+    handler: impl Fn(&str, &[&str]) -> Option<proc_macro2::TokenStream>,
+) -> syn::Result<Vec<proc_macro2::TokenStream>> {
+    let variants = match &input_ast.data {
+        Enum(DataEnum { variants, .. }) => variants,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input_ast.ident,
+                format!("{} can only be derived for an enum", derive_name),
+            ))
+        }
     };
 
-    // push the default fields onto the input
-    if let syn::Fields::Named(FieldsNamed { named, .. }) = input_fields_data {
-        named.extend(default_fields);
+    let mut arms = vec![];
+    for variant in variants {
+        let Fields::Named(FieldsNamed { named, .. }) = &variant.fields else {
+            continue;
+        };
+        let fields: Vec<&syn::Ident> = named.iter().filter_map(|field| field.ident.as_ref()).collect();
+        let names: Vec<String> = fields.iter().map(|field| field.to_string()).collect();
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+
+        if let Some(handler) = handler(&variant.ident.to_string(), &names) {
+            let ident = &variant.ident;
+            arms.push(quote! {
+                Self::#ident { #(#fields),* } => ::ownable_std::Dispatch::Handled(#handler),
+            });
+        }
     }
-
-    quote! { #input_ast }.into()
+    Ok(arms)
 }
 
-
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,300 @@
+use quote::quote;
+use syn::parse::Parser;
+
+use super::*;
+
+// expands an attribute macro and pretty prints the result
+fn expand(
+    expand: impl FnOnce(AttributeArgs, DeriveInput) -> syn::Result<proc_macro2::TokenStream>,
+    args: proc_macro2::TokenStream,
+    input: proc_macro2::TokenStream,
+) -> String {
+    let args = AttributeArgs::parse_terminated.parse2(args).unwrap();
+    let input = syn::parse2(input).unwrap();
+    let output = expand(args, input).unwrap();
+    prettyplease::unparse(&syn::parse2(output).unwrap())
+}
+
+fn expand_err(
+    expand: impl FnOnce(AttributeArgs, DeriveInput) -> syn::Result<proc_macro2::TokenStream>,
+    args: proc_macro2::TokenStream,
+    input: proc_macro2::TokenStream,
+) -> String {
+    let args = AttributeArgs::parse_terminated.parse2(args).unwrap();
+    let input = syn::parse2(input).unwrap();
+    expand(args, input).unwrap_err().to_string()
+}
+
+fn single(
+    macro_name: &'static str,
+    capability: fn() -> Capability,
+) -> impl FnOnce(AttributeArgs, DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    move |args, input| expand_capability(args, input, macro_name, capability())
+}
+
+#[test]
+fn ownables_transfer() {
+    insta::assert_snapshot!(expand(
+        single("ownables_transfer", transfer_capability),
+        quote!(),
+        quote! {
+            #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+            #[serde(rename_all = "snake_case")]
+            pub enum ExecuteMsg {
+                SetColor { color: String },
+            }
+        },
+    ));
+}
+
+#[test]
+fn ownables_lock() {
+    insta::assert_snapshot!(expand(
+        single("ownables_lock", lock_capability),
+        quote!(),
+        quote!(pub enum ExecuteMsg {}),
+    ));
+}
+
+#[test]
+fn ownables_consume() {
+    insta::assert_snapshot!(expand(
+        single("ownables_consume", consume_capability),
+        quote!(),
+        quote!(pub enum ExecuteMsg {}),
+    ));
+}
+
+#[test]
+fn ownables_query_metadata() {
+    insta::assert_snapshot!(expand(
+        single("ownables_query_metadata", metadata_capability),
+        quote!(),
+        quote!(pub enum QueryMsg {}),
+    ));
+}
+
+#[test]
+fn ownables_query_info() {
+    insta::assert_snapshot!(expand(
+        single("ownables_query_info", info_capability),
+        quote!(),
+        quote!(pub enum QueryMsg {}),
+    ));
+}
+
+#[test]
+fn ownables_query_widget_state() {
+    insta::assert_snapshot!(expand(
+        single("ownables_query_widget_state", widget_state_capability),
+        quote!(),
+        quote!(pub enum QueryMsg {}),
+    ));
+}
+
+#[test]
+fn ownables_query_locked() {
+    insta::assert_snapshot!(expand(
+        single("ownables_query_locked", locked_capability),
+        quote!(),
+        quote!(pub enum QueryMsg {}),
+    ));
+}
+
+#[test]
+fn ownables_query_balance() {
+    insta::assert_snapshot!(expand(
+        single("ownables_query_balance", balance_capability),
+        quote!(),
+        quote!(pub enum QueryMsg {}),
+    ));
+}
+
+#[test]
+fn ownables_query_royalty_info() {
+    insta::assert_snapshot!(expand(
+        single("ownables_query_royalty_info", royalty_info_capability),
+        quote!(),
+        quote!(pub enum QueryMsg {}),
+    ));
+}
+
+#[test]
+fn ownables_query_consumer_of() {
+    insta::assert_snapshot!(expand(
+        single("ownables_query_consumer_of", consumer_of_capability),
+        quote!(),
+        quote!(pub enum QueryMsg {}),
+    ));
+}
+
+#[test]
+fn ownables_instantiate_msg() {
+    insta::assert_snapshot!(expand(
+        expand_instantiate_msg,
+        quote!(),
+        quote! {
+            #[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+            pub struct InstantiateMsg {
+                pub color: String,
+            }
+        },
+    ));
+}
+
+#[test]
+fn ownable() {
+    insta::assert_snapshot!(expand(
+        |args, input| expand_capabilities(args, input, "ownable", execute_capability),
+        quote!(transfer, lock, consume),
+        quote!(pub enum ExecuteMsg {}),
+    ));
+}
+
+#[test]
+fn ownable_query() {
+    insta::assert_snapshot!(expand(
+        |args, input| expand_capabilities(args, input, "ownable_query", query_capability),
+        quote!(info, metadata, locked, widget_state, consumer_of),
+        quote!(pub enum QueryMsg<T> where T: Clone {}),
+    ));
+}
+
+#[test]
+fn errors() {
+    assert_eq!(
+        expand_err(
+            single("ownables_lock", lock_capability),
+            quote!(),
+            quote!(pub struct ExecuteMsg {}),
+        ),
+        "ownables_lock can only be applied to an enum"
+    );
+    assert_eq!(
+        expand_err(
+            single("ownables_lock", lock_capability),
+            quote!(reason, reason),
+            quote!(pub enum ExecuteMsg {}),
+        ),
+        "duplicate argument `reason`"
+    );
+    assert_eq!(
+        expand_err(
+            |args, input| expand_capabilities(args, input, "ownable", execute_capability),
+            quote!(transfer, burn),
+            quote!(pub enum ExecuteMsg {}),
+        ),
+        "unknown ownable capability `burn`"
+    );
+    assert_eq!(
+        expand_err(
+            single("ownables_transfer", transfer_capability),
+            quote!(fee),
+            quote!(pub enum ExecuteMsg {}),
+        ),
+        "unknown argument `fee`, ownables_transfer accepts: memo"
+    );
+    assert_eq!(
+        expand_err(
+            |args, input| expand_capabilities(args, input, "ownable_query", query_capability),
+            quote!(info(owner)),
+            quote!(pub enum QueryMsg {}),
+        ),
+        "info takes no arguments"
+    );
+    assert_eq!(
+        expand_err(
+            expand_instantiate_msg,
+            quote!(ownable_id),
+            quote!(pub struct InstantiateMsg {}),
+        ),
+        "no args expected"
+    );
+    assert_eq!(
+        expand_err(
+            expand_instantiate_msg,
+            quote!(),
+            quote!(pub struct InstantiateMsg { network_id: u8 }),
+        ),
+        "field `network_id` is added by ownables_instantiate_msg"
+    );
+}
+
+#[test]
+fn query_responses() {
+    insta::assert_snapshot!(expand(
+        |args, input| expand_capabilities(args, input, "ownable_query", query_capability),
+        quote!(info, metadata, locked, widget_state, consumer_of),
+        quote! {
+            #[derive(QueryResponses)]
+            pub enum QueryMsg {}
+        },
+    ));
+}
+
+#[test]
+fn derive_ownable_execute() {
+    let input = syn::parse2(quote! {
+        pub enum ExecuteMsg {
+            Transfer { to: Addr },
+            Lock {},
+            Consume { amount: Uint128 },
+            Approve { spender: Addr, expires: Option<Timestamp> },
+            Revoke { spender: Addr },
+            ApproveAll { operator: Addr, expires: Option<Timestamp> },
+            RevokeAll { operator: Addr },
+            SetColor { color: String },
+        }
+    })
+    .unwrap();
+    let output = expand_ownable_execute(input).unwrap();
+    insta::assert_snapshot!(prettyplease::unparse(&syn::parse2(output).unwrap()));
+}
+
+#[test]
+fn derive_ownable_query() {
+    let input = syn::parse2(quote! {
+        pub enum QueryMsg {
+            GetInfo {},
+            GetMetadata {},
+            IsLocked {},
+            IsConsumerOf { issuer: Addr, consumable_type: String },
+            GetBalance {},
+            RoyaltyInfo { sale_price: Uint128 },
+            GetApprovals {},
+            // not the IsLocked variant of ownables_query_locked
+            IsLocked2 { since: u64 },
+        }
+    })
+    .unwrap();
+    let output = expand_ownable_query(input).unwrap();
+    insta::assert_snapshot!(prettyplease::unparse(&syn::parse2(output).unwrap()));
+}
+
+#[test]
+fn capability_args() {
+    insta::assert_snapshot!(expand(
+        |args, input| expand_capabilities(args, input, "ownable", execute_capability),
+        quote!(transfer(memo), lock(reason), consume(amount)),
+        quote!(pub enum ExecuteMsg {}),
+    ));
+    insta::assert_snapshot!(expand(
+        single("ownables_transfer", transfer_capability),
+        quote!(memo),
+        quote!(pub enum ExecuteMsg {}),
+    ));
+}
+
+#[test]
+fn approvals() {
+    insta::assert_snapshot!(expand(
+        |args, input| expand_capabilities(args, input, "ownable", execute_capability),
+        quote!(transfer, approve, revoke, approve_all, revoke_all),
+        quote!(pub enum ExecuteMsg {}),
+    ));
+    insta::assert_snapshot!(expand(
+        |args, input| expand_capabilities(args, input, "ownable_query", query_capability),
+        quote!(approvals),
+        quote!(pub enum QueryMsg {}),
+    ));
+}
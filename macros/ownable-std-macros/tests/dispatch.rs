@@ -0,0 +1,207 @@
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{from_json, Addr, DepsMut, Response, Timestamp, Uint128};
+use ownable_std::approval::ApprovalsResponse;
+use ownable_std::consumable::{init_balance, BalanceResponse};
+use ownable_std::royalty::{init_royalty, Royalty, RoyaltyInfoResponse};
+use ownable_std::lock::IsLockedResponse;
+use ownable_std::ownership::init_ownership;
+use ownable_std::{Dispatch, InfoResponse, OwnableError, OwnableInfo};
+use ownable_std_macros::{ownable, ownable_query, OwnableExecute, OwnableQuery};
+use serde::{Deserialize, Serialize};
+
+#[ownable(transfer, lock, consume)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, OwnableExecute)]
+pub enum ExecuteMsg {
+    SetColor { color: String },
+}
+
+#[ownable_query(info, locked, metadata)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, OwnableQuery)]
+pub enum QueryMsg {
+    GetColor {},
+}
+
+fn execute(mut deps: DepsMut, msg: ExecuteMsg, sender: &str) -> Result<Response, OwnableError> {
+    let env = mock_env();
+    let info = mock_info(sender, &[]);
+    msg.dispatch_ownable(&mut deps, &env, &info).or_else(|msg| match msg {
+        ExecuteMsg::SetColor { color } => Ok(Response::new().add_attribute("color", color)),
+        _ => unreachable!(),
+    })
+}
+
+#[test]
+fn dispatch_execute() {
+    let mut deps = mock_dependencies();
+    let ownable_info = OwnableInfo {
+        owner: Addr::unchecked("owner"),
+        issuer: Addr::unchecked("issuer"),
+        ownable_type: None,
+    };
+    init_ownership(deps.as_mut().storage, &ownable_info, None).unwrap();
+
+    let res = execute(deps.as_mut(), ExecuteMsg::SetColor { color: "red".into() }, "owner").unwrap();
+    assert_eq!(res.attributes[0].value, "red");
+
+    let err = execute(deps.as_mut(), ExecuteMsg::Lock {}, "stranger").unwrap_err();
+    assert!(matches!(err, OwnableError::Unauthorized { .. }));
+
+    let res = execute(deps.as_mut(), ExecuteMsg::Transfer { to: Addr::unchecked("buyer") }, "owner").unwrap();
+    assert_eq!(res.attributes[0].value, "transfer");
+
+    execute(deps.as_mut(), ExecuteMsg::Lock {}, "buyer").unwrap();
+    let err = execute(deps.as_mut(), ExecuteMsg::Consume {}, "buyer").unwrap_err();
+    assert!(matches!(err, OwnableError::LockError { .. }));
+}
+
+#[test]
+fn dispatch_query() {
+    let mut deps = mock_dependencies();
+    let ownable_info = OwnableInfo {
+        owner: Addr::unchecked("owner"),
+        issuer: Addr::unchecked("issuer"),
+        ownable_type: Some("potion".into()),
+    };
+    init_ownership(deps.as_mut().storage, &ownable_info, None).unwrap();
+
+    let Dispatch::Handled(binary) = QueryMsg::GetInfo {}.dispatch_ownable(deps.as_ref(), &mock_env()) else {
+        panic!("GetInfo is handled");
+    };
+    let info: InfoResponse = from_json(binary.unwrap()).unwrap();
+    assert_eq!(info.owner, Addr::unchecked("owner"));
+    assert_eq!(info.ownable_type.as_deref(), Some("potion"));
+
+    let Dispatch::Handled(binary) = QueryMsg::IsLocked {}.dispatch_ownable(deps.as_ref(), &mock_env()) else {
+        panic!("IsLocked is handled");
+    };
+    let locked: IsLockedResponse = from_json(binary.unwrap()).unwrap();
+    assert!(!locked.is_locked);
+
+    // metadata depends on the contract, it's not handled
+    assert_eq!(QueryMsg::GetMetadata {}.dispatch_ownable(deps.as_ref(), &mock_env()), Dispatch::Custom(QueryMsg::GetMetadata {}));
+    assert_eq!(QueryMsg::GetColor {}.dispatch_ownable(deps.as_ref(), &mock_env()), Dispatch::Custom(QueryMsg::GetColor {}));
+}
+
+#[ownable(transfer(memo), lock(reason))]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, OwnableExecute)]
+pub enum MemoExecuteMsg {}
+
+#[test]
+fn dispatch_execute_with_args() {
+    let mut deps = mock_dependencies();
+    let ownable_info = OwnableInfo {
+        owner: Addr::unchecked("owner"),
+        issuer: Addr::unchecked("issuer"),
+        ownable_type: None,
+    };
+    init_ownership(deps.as_mut().storage, &ownable_info, None).unwrap();
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+
+    let msg = MemoExecuteMsg::Transfer { to: Addr::unchecked("buyer"), memo: Some("gift".into()) };
+    let Dispatch::Handled(res) = msg.dispatch_ownable(&mut deps.as_mut(), &env, &info) else {
+        panic!("Transfer is handled");
+    };
+    let res = res.unwrap();
+    assert_eq!(res.attributes.last().unwrap().key, "memo");
+    assert_eq!(res.attributes.last().unwrap().value, "gift");
+
+    let info = mock_info("buyer", &[]);
+    let msg = MemoExecuteMsg::Lock { reason: Some("bridge".into()) };
+    let Dispatch::Handled(res) = msg.dispatch_ownable(&mut deps.as_mut(), &env, &info) else {
+        panic!("Lock is handled");
+    };
+    assert_eq!(res.unwrap().attributes.last().unwrap().value, "bridge");
+}
+
+#[ownable(consume(amount))]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, OwnableExecute)]
+pub enum AmountExecuteMsg {}
+
+#[ownable_query(balance, royalty_info)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, OwnableQuery)]
+pub enum ExtraQueryMsg {}
+
+#[test]
+fn dispatch_consume_amount() {
+    let mut deps = mock_dependencies();
+    let ownable_info = OwnableInfo {
+        owner: Addr::unchecked("owner"),
+        issuer: Addr::unchecked("issuer"),
+        ownable_type: None,
+    };
+    init_ownership(deps.as_mut().storage, &ownable_info, None).unwrap();
+    init_balance(deps.as_mut().storage, Uint128::new(5)).unwrap();
+    let env = mock_env();
+    let info = mock_info("owner", &[]);
+
+    let msg = AmountExecuteMsg::Consume { amount: Uint128::new(2) };
+    let Dispatch::Handled(res) = msg.dispatch_ownable(&mut deps.as_mut(), &env, &info) else {
+        panic!("Consume is handled");
+    };
+    res.unwrap();
+
+    let Dispatch::Handled(binary) = ExtraQueryMsg::GetBalance {}.dispatch_ownable(deps.as_ref(), &mock_env()) else {
+        panic!("GetBalance is handled");
+    };
+    let balance: BalanceResponse = from_json(binary.unwrap()).unwrap();
+    assert_eq!(balance.balance, Uint128::new(3));
+}
+
+#[test]
+fn dispatch_royalty_info() {
+    let mut deps = mock_dependencies();
+    init_royalty(deps.as_mut().storage, &Royalty::new(Addr::unchecked("creator"), 1_000).unwrap()).unwrap();
+
+    let msg = ExtraQueryMsg::RoyaltyInfo { sale_price: Uint128::new(50) };
+    let Dispatch::Handled(binary) = msg.dispatch_ownable(deps.as_ref(), &mock_env()) else {
+        panic!("RoyaltyInfo is handled");
+    };
+    let royalty: RoyaltyInfoResponse = from_json(binary.unwrap()).unwrap();
+    assert_eq!(royalty.address, "creator");
+    assert_eq!(royalty.royalty_amount, Uint128::new(5));
+}
+
+#[ownable(transfer, approve, revoke, approve_all, revoke_all)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, OwnableExecute)]
+pub enum ApprovalExecuteMsg {}
+
+#[ownable_query(approvals)]
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, OwnableQuery)]
+pub enum ApprovalQueryMsg {}
+
+#[test]
+fn dispatch_approvals() {
+    let mut deps = mock_dependencies();
+    let ownable_info = OwnableInfo {
+        owner: Addr::unchecked("owner"),
+        issuer: Addr::unchecked("issuer"),
+        ownable_type: None,
+    };
+    init_ownership(deps.as_mut().storage, &ownable_info, None).unwrap();
+    let env = mock_env();
+
+    let msg = ApprovalExecuteMsg::Approve { spender: Addr::unchecked("spender"), expires: None };
+    let Dispatch::Handled(res) = msg.dispatch_ownable(&mut deps.as_mut(), &env, &mock_info("owner", &[])) else {
+        panic!("Approve is handled");
+    };
+    res.unwrap();
+
+    let Dispatch::Handled(binary) = ApprovalQueryMsg::GetApprovals {}.dispatch_ownable(deps.as_ref(), &env) else {
+        panic!("GetApprovals is handled");
+    };
+    let approvals: ApprovalsResponse = from_json(binary.unwrap()).unwrap();
+    assert_eq!(approvals.approvals[0].spender, "spender");
+
+    let msg = ApprovalExecuteMsg::Transfer { to: Addr::unchecked("buyer") };
+    let Dispatch::Handled(res) = msg.dispatch_ownable(&mut deps.as_mut(), &env, &mock_info("spender", &[])) else {
+        panic!("Transfer is handled");
+    };
+    res.unwrap();
+
+    let Dispatch::Handled(binary) = ApprovalQueryMsg::GetApprovals {}.dispatch_ownable(deps.as_ref(), &env) else {
+        panic!("GetApprovals is handled");
+    };
+    let approvals: ApprovalsResponse = from_json(binary.unwrap()).unwrap();
+    assert!(approvals.approvals.is_empty());
+}
@@ -0,0 +1,8 @@
+use ownable_std_macros::ownables_consume;
+
+#[ownables_consume]
+pub enum ExecuteMsg {
+    Consume {},
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use ownable_std_macros::ownables_consume;
+
+#[ownables_consume]
+pub struct ExecuteMsg {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use ownable_std_macros::OwnableExecute;
+
+#[derive(OwnableExecute)]
+pub struct ExecuteMsg {}
+
+fn main() {}
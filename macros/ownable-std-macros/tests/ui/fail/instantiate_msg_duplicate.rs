@@ -0,0 +1,8 @@
+use ownable_std_macros::ownables_instantiate_msg;
+
+#[ownables_instantiate_msg]
+pub struct InstantiateMsg {
+    pub package: String,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use ownable_std_macros::ownables_instantiate_msg;
+
+#[ownables_instantiate_msg]
+pub enum InstantiateMsg {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ownable_std_macros::ownables_lock;
+
+#[ownables_lock]
+pub enum ExecuteMsg {
+    Lock {},
+}
+
+fn main() {}
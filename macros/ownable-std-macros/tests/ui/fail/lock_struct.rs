@@ -0,0 +1,6 @@
+use ownable_std_macros::ownables_lock;
+
+#[ownables_lock]
+pub struct ExecuteMsg {}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use ownable_std_macros::ownable;
+
+#[ownable(transfer, lock, transfer)]
+pub enum ExecuteMsg {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ownable_std_macros::ownable;
+
+#[ownable(transfer, lock)]
+pub enum ExecuteMsg {
+    Lock { reason: String },
+}
+
+fn main() {}
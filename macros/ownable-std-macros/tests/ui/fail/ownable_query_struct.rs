@@ -0,0 +1,6 @@
+use ownable_std_macros::ownable_query;
+
+#[ownable_query(info)]
+pub struct QueryMsg {}
+
+fn main() {}
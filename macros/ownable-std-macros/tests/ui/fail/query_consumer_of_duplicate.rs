@@ -0,0 +1,8 @@
+use ownable_std_macros::ownables_query_consumer_of;
+
+#[ownables_query_consumer_of]
+pub enum QueryMsg {
+    IsConsumerOf {},
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use ownable_std_macros::ownables_query_consumer_of;
+
+#[ownables_query_consumer_of]
+pub struct QueryMsg {}
+
+fn main() {}
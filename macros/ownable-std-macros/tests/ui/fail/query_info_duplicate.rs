@@ -0,0 +1,8 @@
+use ownable_std_macros::ownables_query_info;
+
+#[ownables_query_info]
+pub enum QueryMsg {
+    GetInfo {},
+}
+
+fn main() {}
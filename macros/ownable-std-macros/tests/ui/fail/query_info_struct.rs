@@ -0,0 +1,6 @@
+use ownable_std_macros::ownables_query_info;
+
+#[ownables_query_info]
+pub struct QueryMsg {}
+
+fn main() {}
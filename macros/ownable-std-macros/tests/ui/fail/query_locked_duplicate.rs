@@ -0,0 +1,8 @@
+use ownable_std_macros::ownables_query_locked;
+
+#[ownables_query_locked]
+pub enum QueryMsg {
+    IsLocked {},
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use ownable_std_macros::ownables_query_locked;
+
+#[ownables_query_locked]
+pub struct QueryMsg {}
+
+fn main() {}
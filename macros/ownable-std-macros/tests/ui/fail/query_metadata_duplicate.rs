@@ -0,0 +1,8 @@
+use ownable_std_macros::ownables_query_metadata;
+
+#[ownables_query_metadata]
+pub enum QueryMsg {
+    GetMetadata {},
+}
+
+fn main() {}
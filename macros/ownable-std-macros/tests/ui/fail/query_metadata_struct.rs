@@ -0,0 +1,6 @@
+use ownable_std_macros::ownables_query_metadata;
+
+#[ownables_query_metadata]
+pub struct QueryMsg {}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use ownable_std_macros::ownables_query_widget_state;
+
+#[ownables_query_widget_state]
+pub enum QueryMsg {
+    GetWidgetState {},
+}
+
+fn main() {}
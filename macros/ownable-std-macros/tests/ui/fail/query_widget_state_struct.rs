@@ -0,0 +1,6 @@
+use ownable_std_macros::ownables_query_widget_state;
+
+#[ownables_query_widget_state]
+pub struct QueryMsg {}
+
+fn main() {}
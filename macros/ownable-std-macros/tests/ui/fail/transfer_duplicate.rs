@@ -0,0 +1,8 @@
+use ownable_std_macros::ownables_transfer;
+
+#[ownables_transfer]
+pub enum ExecuteMsg {
+    Transfer { to: String },
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use ownable_std_macros::ownables_transfer;
+
+#[ownables_transfer(memo, fee)]
+pub enum ExecuteMsg {}
+
+fn main() {}
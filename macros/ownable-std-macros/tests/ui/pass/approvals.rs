@@ -0,0 +1,18 @@
+use cosmwasm_std::{Addr, Timestamp};
+use ownable_std_macros::{ownable, ownable_query};
+
+#[ownable(transfer, approve, revoke, approve_all, revoke_all)]
+pub enum ExecuteMsg {}
+
+#[ownable_query(approvals)]
+pub enum QueryMsg {}
+
+fn main() {
+    let _ = ExecuteMsg::Approve {
+        spender: Addr::unchecked("spender"),
+        expires: Some(Timestamp::from_seconds(100)),
+    };
+    let _ = ExecuteMsg::RevokeAll { operator: Addr::unchecked("operator") };
+    let _ = QueryMsg::GetApprovals {};
+    assert_eq!(QueryMsg::OWNABLE_CAPABILITIES, ["approvals"]);
+}
@@ -0,0 +1,16 @@
+use cosmwasm_std::Addr;
+use ownable_std_macros::{ownable, ownable_query};
+
+#[ownable(transfer, lock, consume)]
+pub enum ExecuteMsg {
+    SetColor { color: String },
+}
+
+#[ownable_query(info, metadata, locked)]
+pub enum QueryMsg {}
+
+fn main() {
+    let _ = ExecuteMsg::Transfer { to: Addr::unchecked("owner") };
+    assert_eq!(ExecuteMsg::OWNABLE_CAPABILITIES, ["transfer", "lock", "consume"]);
+    assert_eq!(QueryMsg::OWNABLE_CAPABILITIES, ["info", "metadata", "locked"]);
+}
@@ -0,0 +1,20 @@
+use cosmwasm_std::{Addr, Uint128};
+use ownable_std_macros::{ownable, ownables_consume, ownables_lock, ownables_transfer};
+
+#[ownables_transfer(memo)]
+#[ownables_lock(reason)]
+#[ownables_consume(amount)]
+pub enum ExecuteMsg {}
+
+#[ownable(transfer(memo), lock, consume(amount))]
+pub enum OtherExecuteMsg {}
+
+fn main() {
+    let _ = ExecuteMsg::Transfer { to: Addr::unchecked("buyer"), memo: Some("gift".to_string()) };
+    let _ = ExecuteMsg::Lock { reason: None };
+    let _ = ExecuteMsg::Consume { amount: Uint128::new(2) };
+    let _ = OtherExecuteMsg::Transfer { to: Addr::unchecked("buyer"), memo: None };
+    let _ = OtherExecuteMsg::Lock {};
+    let _ = OtherExecuteMsg::Consume { amount: Uint128::one() };
+    assert_eq!(OtherExecuteMsg::OWNABLE_CAPABILITIES, ["transfer", "lock", "consume"]);
+}
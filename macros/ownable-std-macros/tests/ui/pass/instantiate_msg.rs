@@ -0,0 +1,21 @@
+use ownable_std::{OwnableInstantiate, NFT};
+use ownable_std_macros::ownables_instantiate_msg;
+
+#[ownables_instantiate_msg]
+pub struct InstantiateMsg {
+    pub color: String,
+}
+
+fn main() {
+    let msg = InstantiateMsg {
+        color: "#ff0000".to_string(),
+        ownable_id: "2bJ69cFXzS8AJTcCmzjc9oeHZmBrmMVUr8svJ1mTGpho".to_string(),
+        package: "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+        nft: None,
+        ownable_type: None,
+        network_id: b'T',
+    };
+    assert_eq!(msg.ownable_id(), "2bJ69cFXzS8AJTcCmzjc9oeHZmBrmMVUr8svJ1mTGpho");
+    assert!(msg.nft().is_none());
+    let _: Option<&NFT> = msg.nft();
+}
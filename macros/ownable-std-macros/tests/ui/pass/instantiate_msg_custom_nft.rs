@@ -0,0 +1,36 @@
+use ownable_std::instantiate::NftInfo;
+use ownable_std::OwnableInstantiate;
+use ownable_std_macros::ownables_instantiate_msg;
+
+pub struct NFT {
+    pub chain_id: String,
+    pub contract: String,
+}
+
+impl NftInfo for NFT {
+    fn network(&self) -> &str {
+        &self.chain_id
+    }
+
+    fn address(&self) -> &str {
+        &self.contract
+    }
+}
+
+#[ownables_instantiate_msg]
+pub struct InstantiateMsg {}
+
+fn main() {
+    let msg = InstantiateMsg {
+        ownable_id: "2bJ69cFXzS8AJTcCmzjc9oeHZmBrmMVUr8svJ1mTGpho".to_string(),
+        package: "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+        nft: Some(NFT {
+            chain_id: "eip155:1".to_string(),
+            contract: "0x341".to_string(),
+        }),
+        ownable_type: None,
+        network_id: b'T',
+    };
+    let _: Option<&NFT> = msg.nft();
+    assert!(msg.validate().is_ok());
+}
@@ -0,0 +1,39 @@
+use ownable_std::OwnableInstantiate;
+use ownable_std_macros::ownables_instantiate_msg;
+
+mod state {
+    pub use ownable_std::NFT;
+}
+
+mod msg {
+    use super::state::NFT;
+    use ownable_std_macros::ownables_instantiate_msg;
+
+    #[ownables_instantiate_msg]
+    pub struct InstantiateMsg {}
+}
+
+#[ownables_instantiate_msg]
+pub struct AliasedMsg {}
+
+type NFT = ownable_std::NFT;
+
+fn main() {
+    let msg = msg::InstantiateMsg {
+        ownable_id: "2bJ69cFXzS8AJTcCmzjc9oeHZmBrmMVUr8svJ1mTGpho".to_string(),
+        package: "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".to_string(),
+        nft: None,
+        ownable_type: None,
+        network_id: b'T',
+    };
+    let _: Option<&ownable_std::NFT> = msg.nft();
+
+    let aliased = AliasedMsg {
+        ownable_id: msg.ownable_id,
+        package: msg.package,
+        nft: None,
+        ownable_type: None,
+        network_id: b'T',
+    };
+    let _: Option<&ownable_std::NFT> = aliased.nft();
+}
@@ -0,0 +1,29 @@
+use cosmwasm_schema::{cw_serde, QueryResponses};
+use cosmwasm_std::Addr;
+use ownable_std_macros::{ownable_query, ownables_query_info};
+
+#[ownable_query(metadata, locked, widget_state, consumer_of)]
+#[ownables_query_info]
+#[cw_serde]
+#[derive(QueryResponses)]
+pub enum QueryMsg {}
+
+// without QueryResponses no #[returns] is added
+#[ownables_query_info]
+#[cw_serde]
+pub enum PlainQueryMsg {}
+
+fn main() {
+    let responses = QueryMsg::response_schemas().unwrap();
+    assert_eq!(responses["get_info"].schema.metadata.as_ref().unwrap().title.as_deref(), Some("InfoResponse"));
+    assert_eq!(responses["get_metadata"].schema.metadata.as_ref().unwrap().title.as_deref(), Some("Metadata"));
+    assert_eq!(responses["is_locked"].schema.metadata.as_ref().unwrap().title.as_deref(), Some("IsLockedResponse"));
+    assert!(responses.contains_key("is_consumer_of"));
+
+    let _ = PlainQueryMsg::GetInfo {};
+    let _ = QueryMsg::IsConsumerOf { issuer: Addr::unchecked("issuer"), consumable_type: "potion".to_string() };
+
+    // the doc comments end up in the schema
+    let schema = serde_json::to_string(&schemars::schema_for!(QueryMsg)).unwrap();
+    assert!(schema.contains("whether the ownable can consume ownables of the given issuer and type"));
+}
@@ -0,0 +1,35 @@
+use cosmwasm_std::{Addr, Uint128};
+use ownable_std_macros::{
+    ownables_consume, ownables_lock, ownables_query_balance, ownables_query_consumer_of,
+    ownables_query_info, ownables_query_locked, ownables_query_metadata,
+    ownables_query_royalty_info, ownables_query_widget_state, ownables_transfer,
+};
+
+#[ownables_transfer]
+#[ownables_lock]
+#[ownables_consume]
+pub enum ExecuteMsg {}
+
+#[ownables_query_metadata]
+#[ownables_query_info]
+#[ownables_query_widget_state]
+#[ownables_query_locked]
+#[ownables_query_consumer_of]
+#[ownables_query_balance]
+#[ownables_query_royalty_info]
+pub enum QueryMsg {
+    GetColor {},
+}
+
+fn main() {
+    let _ = ExecuteMsg::Transfer { to: Addr::unchecked("owner") };
+    let _ = ExecuteMsg::Lock {};
+    let _ = ExecuteMsg::Consume {};
+    let _ = QueryMsg::GetColor {};
+    let _ = QueryMsg::GetBalance {};
+    let _ = QueryMsg::RoyaltyInfo { sale_price: Uint128::new(100) };
+    let _ = QueryMsg::IsConsumerOf {
+        issuer: Addr::unchecked("issuer"),
+        consumable_type: "potion".to_string(),
+    };
+}
@@ -0,0 +1,226 @@
+use cosmwasm_std::{
+    Addr, Deps, DepsMut, Env, MessageInfo, Order, Response, StdResult, Storage, Timestamp,
+};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::ownership::{OWNABLE_INFO, assert_owner};
+use crate::{OwnableError, OwnableInfo};
+
+/// spenders approved to transfer the ownable, with their expiry. cleared
+/// when the ownable is transferred
+pub const APPROVALS: Map<&Addr, Option<Timestamp>> = Map::new("approvals");
+
+/// operators approved by the owner to transfer this ownable and approve
+/// spenders for it, keyed by (owner, operator). every ownable has its own
+/// state, so an operator for all ownables of an owner is approved on each
+/// of them. operators are cleared when the ownable is transferred, so they
+/// don't carry over to a new owner or come back with the previous one
+pub const OPERATORS: Map<(&Addr, &Addr), Option<Timestamp>> = Map::new("operators");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct Approval {
+    pub spender: Addr,
+    /// block time at which the approval expires, never if not set
+    pub expires: Option<Timestamp>,
+}
+
+impl Approval {
+    pub fn is_expired(&self, env: &Env) -> bool {
+        is_expired(self.expires, env)
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ApprovalsResponse {
+    pub approvals: Vec<Approval>,
+    /// operators of the current owner
+    pub operators: Vec<Approval>,
+}
+
+fn is_expired(expires: Option<Timestamp>, env: &Env) -> bool {
+    expires.is_some_and(|expires| env.block.time >= expires)
+}
+
+fn assert_not_expired(expires: Option<Timestamp>, env: &Env) -> Result<(), OwnableError> {
+    if is_expired(expires, env) {
+        return Err(OwnableError::ApprovalError {
+            val: "expiry is in the past".into(),
+        });
+    }
+    Ok(())
+}
+
+/// returns whether `operator` is an operator of `owner` for this ownable
+pub fn is_operator(
+    storage: &dyn Storage,
+    env: &Env,
+    owner: &Addr,
+    operator: &Addr,
+) -> StdResult<bool> {
+    Ok(OPERATORS
+        .may_load(storage, (owner, operator))?
+        .is_some_and(|expires| !is_expired(expires, env)))
+}
+
+/// returns whether `sender` may transfer the ownable: the owner, an
+/// approved spender or an operator of the owner, unless expired
+pub fn is_approved(
+    storage: &dyn Storage,
+    env: &Env,
+    ownable_info: &OwnableInfo,
+    sender: &Addr,
+) -> StdResult<bool> {
+    if ownable_info.owner == *sender {
+        return Ok(true);
+    }
+    if APPROVALS
+        .may_load(storage, sender)?
+        .is_some_and(|expires| !is_expired(expires, env))
+    {
+        return Ok(true);
+    }
+    is_operator(storage, env, &ownable_info.owner, sender)
+}
+
+/// errors unless the sender may transfer the ownable. returns the loaded
+/// ownership state
+pub fn assert_approved(
+    storage: &dyn Storage,
+    env: &Env,
+    sender: &Addr,
+) -> Result<OwnableInfo, OwnableError> {
+    let ownable_info = OWNABLE_INFO.load(storage)?;
+    if !is_approved(storage, env, &ownable_info, sender)? {
+        return Err(OwnableError::Unauthorized {
+            val: "not the owner or approved".into(),
+        });
+    }
+    Ok(ownable_info)
+}
+
+/// removes all spender approvals, e.g. when the ownable is transferred
+pub fn clear_approvals(storage: &mut dyn Storage) {
+    APPROVALS.clear(storage)
+}
+
+/// removes the operators of `owner`, e.g. when the ownable is transferred,
+/// so they aren't approved again if it is transferred back
+pub fn clear_operators(storage: &mut dyn Storage, owner: &Addr) {
+    OPERATORS.prefix(owner).clear(storage, None)
+}
+
+/// approves a spender to transfer the ownable until `expires`. the owner
+/// and its operators can approve
+pub fn try_approve(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    spender: Addr,
+    expires: Option<Timestamp>,
+) -> Result<Response, OwnableError> {
+    let ownable_info = OWNABLE_INFO.load(deps.storage)?;
+    if ownable_info.owner != info.sender
+        && !is_operator(deps.storage, &env, &ownable_info.owner, &info.sender)?
+    {
+        return Err(OwnableError::Unauthorized {
+            val: "not the owner or an operator".into(),
+        });
+    }
+    assert_not_expired(expires, &env)?;
+    let spender = deps.api.addr_validate(spender.as_str())?;
+
+    APPROVALS.save(deps.storage, &spender, &expires)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "approve")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender))
+}
+
+/// revokes the approval of a spender. the owner and its operators can revoke
+pub fn try_revoke(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    spender: Addr,
+) -> Result<Response, OwnableError> {
+    let ownable_info = OWNABLE_INFO.load(deps.storage)?;
+    if ownable_info.owner != info.sender
+        && !is_operator(deps.storage, &env, &ownable_info.owner, &info.sender)?
+    {
+        return Err(OwnableError::Unauthorized {
+            val: "not the owner or an operator".into(),
+        });
+    }
+
+    APPROVALS.remove(deps.storage, &spender);
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke")
+        .add_attribute("sender", info.sender)
+        .add_attribute("spender", spender))
+}
+
+/// approves an operator to transfer this ownable and approve spenders
+/// for it until `expires`. only the owner can approve operators
+pub fn try_approve_all(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    operator: Addr,
+    expires: Option<Timestamp>,
+) -> Result<Response, OwnableError> {
+    assert_owner(deps.storage, &info.sender)?;
+    assert_not_expired(expires, &env)?;
+    let operator = deps.api.addr_validate(operator.as_str())?;
+
+    OPERATORS.save(deps.storage, (&info.sender, &operator), &expires)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "approve_all")
+        .add_attribute("owner", info.sender)
+        .add_attribute("operator", operator))
+}
+
+/// revokes an operator. only the owner can revoke operators
+pub fn try_revoke_all(
+    deps: DepsMut,
+    info: MessageInfo,
+    operator: Addr,
+) -> Result<Response, OwnableError> {
+    assert_owner(deps.storage, &info.sender)?;
+    OPERATORS.remove(deps.storage, (&info.sender, &operator));
+
+    Ok(Response::new()
+        .add_attribute("action", "revoke_all")
+        .add_attribute("owner", info.sender)
+        .add_attribute("operator", operator))
+}
+
+/// handles the GetApprovals {} query. expired approvals are left out
+pub fn query_approvals(deps: Deps, env: Env) -> StdResult<ApprovalsResponse> {
+    let owner = OWNABLE_INFO.load(deps.storage)?.owner;
+
+    let approvals = APPROVALS
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(spender, expires)| Approval { spender, expires }))
+        .collect::<StdResult<Vec<_>>>()?;
+    let operators = OPERATORS
+        .prefix(&owner)
+        .range(deps.storage, None, None, Order::Ascending)
+        .map(|item| item.map(|(spender, expires)| Approval { spender, expires }))
+        .collect::<StdResult<Vec<_>>>()?;
+
+    Ok(ApprovalsResponse {
+        approvals: approvals
+            .into_iter()
+            .filter(|approval| !approval.is_expired(&env))
+            .collect(),
+        operators: operators
+            .into_iter()
+            .filter(|approval| !approval.is_expired(&env))
+            .collect(),
+    })
+}
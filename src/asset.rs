@@ -0,0 +1,384 @@
+use std::fmt;
+use std::str::FromStr;
+
+use schemars::JsonSchema;
+use schemars::r#gen::SchemaGenerator;
+use schemars::schema::Schema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::Metadata;
+
+pub const DEFAULT_IPFS_GATEWAY: &str = "https://ipfs.io";
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE32_ALPHABET: &[u8] = b"abcdefghijklmnopqrstuvwxyz234567";
+
+// multicodec codes of a CIDv0: dag-pb with a sha2-256 multihash
+const DAG_PB: u64 = 0x70;
+const SHA2_256: u64 = 0x12;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum AssetError {
+    #[error("invalid cid {0}")]
+    InvalidCid(String),
+
+    #[error("unsupported asset uri scheme in {0}")]
+    UnsupportedScheme(String),
+
+    #[error("invalid package path {0}")]
+    InvalidPath(String),
+
+    #[error("cannot resolve {0} without a package cid")]
+    MissingPackage(String),
+}
+
+/// an IPFS content identifier, either a base58btc encoded CIDv0 (Qm...)
+/// or a multibase encoded CIDv1 (bafy...)
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Cid {
+    version: u8,
+    codec: u64,
+    hash_code: u64,
+    digest: Vec<u8>,
+    encoded: String,
+}
+
+impl Cid {
+    pub fn parse(s: &str) -> Result<Cid, AssetError> {
+        let invalid = || AssetError::InvalidCid(s.to_string());
+
+        if s.len() == 46 && s.starts_with("Qm") {
+            let bytes = decode_base58(s).ok_or_else(invalid)?;
+            return match bytes.as_slice() {
+                [0x12, 0x20, digest @ ..] if digest.len() == 32 => Ok(Cid {
+                    version: 0,
+                    codec: DAG_PB,
+                    hash_code: SHA2_256,
+                    digest: digest.to_vec(),
+                    encoded: s.to_string(),
+                }),
+                _ => Err(invalid()),
+            };
+        }
+
+        let mut chars = s.chars();
+        let bytes = match chars.next() {
+            Some('b') => decode_base32(chars.as_str()),
+            Some('B') => decode_base32(&chars.as_str().to_ascii_lowercase()),
+            Some('z') => decode_base58(chars.as_str()),
+            Some('f' | 'F') => hex::decode(chars.as_str()).ok(),
+            _ => None,
+        }
+        .ok_or_else(invalid)?;
+
+        let mut rest = bytes.as_slice();
+        let version = read_varint(&mut rest).ok_or_else(invalid)?;
+        let codec = read_varint(&mut rest).ok_or_else(invalid)?;
+        let hash_code = read_varint(&mut rest).ok_or_else(invalid)?;
+        let digest_len = read_varint(&mut rest).ok_or_else(invalid)?;
+        if version != 1 || digest_len == 0 || digest_len != rest.len() as u64 {
+            return Err(invalid());
+        }
+
+        Ok(Cid {
+            version: 1,
+            codec,
+            hash_code,
+            digest: rest.to_vec(),
+            encoded: s.to_string(),
+        })
+    }
+
+    pub fn version(&self) -> u8 {
+        self.version
+    }
+
+    /// multicodec of the content, e.g. 0x70 for dag-pb or 0x55 for raw
+    pub fn codec(&self) -> u64 {
+        self.codec
+    }
+
+    /// multihash function code, e.g. 0x12 for sha2-256
+    pub fn hash_code(&self) -> u64 {
+        self.hash_code
+    }
+
+    pub fn digest(&self) -> &[u8] {
+        &self.digest
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.encoded
+    }
+}
+
+impl FromStr for Cid {
+    type Err = AssetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Cid::parse(s)
+    }
+}
+
+impl fmt::Display for Cid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.encoded)
+    }
+}
+
+/// reference to an asset of an ownable, as used in `Metadata::image` and
+/// `Metadata::animation_url`. serialized as its uri string
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+#[serde(try_from = "String", into = "String")]
+pub enum AssetRef {
+    /// ipfs://<cid>[/<path>], or a bare cid
+    Ipfs { cid: Cid, path: Option<String> },
+    /// path relative to the root of the ownable package
+    Package(String),
+    /// data:[<media type>][;base64],<data>
+    Data(String),
+    /// https://...
+    Https(String),
+}
+
+impl AssetRef {
+    pub fn parse(s: &str) -> Result<AssetRef, AssetError> {
+        let s = s.trim();
+
+        if let Some(rest) = strip_scheme(s, "ipfs://") {
+            let (cid, path) = match rest.split_once('/') {
+                Some((cid, path)) => (cid, Some(validate_path(path)?)),
+                None => (rest, None),
+            };
+            return Ok(AssetRef::Ipfs {
+                cid: Cid::parse(cid)?,
+                path: path.filter(|path| !path.is_empty()),
+            });
+        }
+        if strip_scheme(s, "data:").is_some() {
+            return Ok(AssetRef::Data(s.to_string()));
+        }
+        if strip_scheme(s, "https://").is_some() {
+            return Ok(AssetRef::Https(s.to_string()));
+        }
+
+        // any other uri scheme, e.g. http: or javascript:
+        if let Some((scheme, _)) = s.split_once(':')
+            && !scheme.contains('/')
+        {
+            return Err(AssetError::UnsupportedScheme(s.to_string()));
+        }
+
+        if let Ok(cid) = Cid::parse(s) {
+            return Ok(AssetRef::Ipfs { cid, path: None });
+        }
+        Ok(AssetRef::Package(validate_path(s)?))
+    }
+}
+
+// uri schemes are case insensitive
+fn strip_scheme<'a>(s: &'a str, scheme: &str) -> Option<&'a str> {
+    s.get(..scheme.len())
+        .filter(|prefix| prefix.eq_ignore_ascii_case(scheme))
+        .map(|_| &s[scheme.len()..])
+}
+
+// normalizes a relative path, rejecting absolute paths and paths that
+// escape the package
+fn validate_path(path: &str) -> Result<String, AssetError> {
+    let invalid = || AssetError::InvalidPath(path.to_string());
+    if path.starts_with('/') || path.contains('\\') {
+        return Err(invalid());
+    }
+
+    let mut segments = vec![];
+    for segment in path.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => return Err(invalid()),
+            _ => segments.push(segment),
+        }
+    }
+    Ok(segments.join("/"))
+}
+
+impl FromStr for AssetRef {
+    type Err = AssetError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AssetRef::parse(s)
+    }
+}
+
+impl TryFrom<String> for AssetRef {
+    type Error = AssetError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        AssetRef::parse(&s)
+    }
+}
+
+impl From<AssetRef> for String {
+    fn from(asset: AssetRef) -> Self {
+        asset.to_string()
+    }
+}
+
+impl fmt::Display for AssetRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AssetRef::Ipfs { cid, path: None } => write!(f, "ipfs://{}", cid),
+            AssetRef::Ipfs {
+                cid,
+                path: Some(path),
+            } => write!(f, "ipfs://{}/{}", cid, path),
+            AssetRef::Package(path) => f.write_str(path),
+            AssetRef::Data(uri) | AssetRef::Https(uri) => f.write_str(uri),
+        }
+    }
+}
+
+impl JsonSchema for AssetRef {
+    fn schema_name() -> String {
+        "AssetRef".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        String::json_schema(generator)
+    }
+}
+
+/// resolves asset references to urls that can be displayed by a browser.
+/// package paths are resolved against the package cid from InstantiateMsg
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AssetResolver {
+    pub ipfs_gateway: String,
+    pub package: Option<Cid>,
+}
+
+impl Default for AssetResolver {
+    fn default() -> Self {
+        AssetResolver {
+            ipfs_gateway: DEFAULT_IPFS_GATEWAY.to_string(),
+            package: None,
+        }
+    }
+}
+
+impl AssetResolver {
+    pub fn new(ipfs_gateway: impl Into<String>) -> Self {
+        AssetResolver {
+            ipfs_gateway: ipfs_gateway.into(),
+            package: None,
+        }
+    }
+
+    pub fn with_package(mut self, package: &str) -> Result<Self, AssetError> {
+        self.package = Some(Cid::parse(package)?);
+        Ok(self)
+    }
+
+    /// paths are percent-encoded, leaving existing %XX escapes as they are
+    pub fn resolve(&self, asset: &AssetRef) -> Result<String, AssetError> {
+        let gateway = self.ipfs_gateway.trim_end_matches('/');
+        match asset {
+            AssetRef::Ipfs { cid, path: None } => Ok(format!("{}/ipfs/{}", gateway, cid)),
+            AssetRef::Ipfs {
+                cid,
+                path: Some(path),
+            } => Ok(format!("{}/ipfs/{}/{}", gateway, cid, encode_path(path))),
+            AssetRef::Package(path) => match &self.package {
+                Some(package) => Ok(format!(
+                    "{}/ipfs/{}/{}",
+                    gateway,
+                    package,
+                    encode_path(path)
+                )),
+                None => Err(AssetError::MissingPackage(path.clone())),
+            },
+            AssetRef::Data(uri) | AssetRef::Https(uri) => Ok(uri.clone()),
+        }
+    }
+}
+
+impl Metadata {
+    pub fn image_ref(&self) -> Option<Result<AssetRef, AssetError>> {
+        self.image.as_deref().map(AssetRef::parse)
+    }
+
+    pub fn animation_url_ref(&self) -> Option<Result<AssetRef, AssetError>> {
+        self.animation_url.as_deref().map(AssetRef::parse)
+    }
+}
+
+// percent-encodes everything but the characters allowed in a url path
+fn encode_path(path: &str) -> String {
+    let bytes = path.as_bytes();
+    let mut out = String::with_capacity(path.len());
+    for (i, &byte) in bytes.iter().enumerate() {
+        let escaped = byte == b'%'
+            && bytes
+                .get(i + 1..i + 3)
+                .is_some_and(|hex| hex.iter().all(u8::is_ascii_hexdigit));
+        if byte.is_ascii_alphanumeric() || b"-._~!$&'()*+,;=:@/".contains(&byte) || escaped {
+            out.push(byte as char);
+        } else {
+            out.push_str(&format!("%{:02X}", byte));
+        }
+    }
+    out
+}
+
+// unsigned LEB128, as used by multiformats. at most 9 bytes
+fn read_varint(bytes: &mut &[u8]) -> Option<u64> {
+    let mut value = 0u64;
+    for (i, byte) in bytes.iter().enumerate().take(9) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            *bytes = &bytes[i + 1..];
+            return Some(value);
+        }
+    }
+    None
+}
+
+fn decode_base58(s: &str) -> Option<Vec<u8>> {
+    // little endian big number, one base256 digit per byte
+    let mut bytes: Vec<u8> = vec![];
+    for c in s.bytes() {
+        let mut carry = BASE58_ALPHABET.iter().position(|&a| a == c)? as u32;
+        for byte in bytes.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            bytes.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    // leading '1's encode leading zero bytes
+    let zeros = s.bytes().take_while(|&c| c == b'1').count();
+    bytes.extend(std::iter::repeat_n(0, zeros));
+    bytes.reverse();
+    Some(bytes)
+}
+
+// rfc4648 base32 without padding
+fn decode_base32(s: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(s.len() * 5 / 8);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in s.bytes() {
+        let value = BASE32_ALPHABET.iter().position(|&a| a == c)? as u32;
+        buffer = (buffer << 5) | value;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    Some(bytes)
+}
@@ -0,0 +1,136 @@
+use std::fmt;
+use std::str::FromStr;
+
+use schemars::JsonSchema;
+use schemars::r#gen::SchemaGenerator;
+use schemars::schema::Schema;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+use crate::hash::HashError;
+use crate::palette::Hsl;
+use crate::try_derive_rgb_values;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum ColorError {
+    #[error("invalid color {0}, expected #RGB, #RGBA, #RRGGBB or #RRGGBBAA")]
+    InvalidFormat(String),
+}
+
+/// an rgb color with alpha channel. serialized as a hex string
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Color { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Color { r, g, b, a }
+    }
+
+    /// derives a color from a hex-encoded hash, like get_random_color
+    pub fn from_hash(hash: &str) -> Result<Self, HashError> {
+        let (r, g, b) = try_derive_rgb_values(hash)?;
+        Ok(Color::rgb(r, g, b))
+    }
+
+    /// parses #RGB, #RGBA, #RRGGBB or #RRGGBBAA. the # is optional, as
+    /// `Metadata::background_color` is written without it
+    pub fn parse(s: &str) -> Result<Self, ColorError> {
+        let invalid = || ColorError::InvalidFormat(s.to_string());
+        let digits = s.trim().strip_prefix('#').unwrap_or(s.trim());
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+
+        let nibble = |i: usize| u8::from_str_radix(&digits[i..=i], 16).unwrap();
+        let byte = |i: usize| u8::from_str_radix(&digits[2 * i..2 * i + 2], 16).unwrap();
+        match digits.len() {
+            // short notation repeats each digit, #F80 is #FF8800
+            3 => Ok(Color::rgb(nibble(0) * 17, nibble(1) * 17, nibble(2) * 17)),
+            4 => Ok(Color::rgba(
+                nibble(0) * 17,
+                nibble(1) * 17,
+                nibble(2) * 17,
+                nibble(3) * 17,
+            )),
+            6 => Ok(Color::rgb(byte(0), byte(1), byte(2))),
+            8 => Ok(Color::rgba(byte(0), byte(1), byte(2), byte(3))),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// formats as #RRGGBB, or #RRGGBBAA if the color isn't opaque
+    pub fn to_hex(&self) -> String {
+        if self.a == 255 {
+            format!("#{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+        } else {
+            format!("#{:02X}{:02X}{:02X}{:02X}", self.r, self.g, self.b, self.a)
+        }
+    }
+
+    /// formats as RRGGBB, the format of `Metadata::background_color`
+    pub fn to_background_color(&self) -> String {
+        format!("{:02X}{:02X}{:02X}", self.r, self.g, self.b)
+    }
+
+    pub fn to_hsl(&self) -> Hsl {
+        Hsl::from_rgb(self.r, self.g, self.b)
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(hsl: Hsl) -> Self {
+        let (r, g, b) = hsl.to_rgb();
+        Color::rgb(r, g, b)
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Color::parse(s)
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = ColorError;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        Color::parse(&s)
+    }
+}
+
+impl From<Color> for String {
+    fn from(color: Color) -> Self {
+        color.to_hex()
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.to_hex())
+    }
+}
+
+impl JsonSchema for Color {
+    fn schema_name() -> String {
+        "Color".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        String::json_schema(generator)
+    }
+}
@@ -0,0 +1,153 @@
+use cosmwasm_std::{Deps, DepsMut, Env, Event, MessageInfo, Response, StdResult, Storage, Uint128};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::OwnableError;
+use crate::events::{CONSUME_EVENT, OwnableEvent};
+use crate::lock::assert_not_locked;
+use crate::ownership::{assert_owner, load_ownable_id};
+
+pub const CONSUMED: Item<bool> = Item::new("is_consumed");
+
+/// remaining units of a fungible consumable, e.g. the tickets left in a
+/// ticket book. not stored for ownables that are consumed all at once
+pub const BALANCE: Item<Uint128> = Item::new("consumable_balance");
+
+/// type of the event emitted when an ownable is consumed
+pub const CONSUME_EVENT_TYPE: &str = CONSUME_EVENT;
+
+/// type of the consume event before the standard ownable events, still
+/// accepted by consumers when it carries the consumable id and remaining
+/// balance
+pub const LEGACY_CONSUME_EVENT_TYPE: &str = "consume";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct BalanceResponse {
+    pub balance: Uint128,
+}
+
+/// returns whether the ownable has been consumed
+pub fn is_consumed(storage: &dyn Storage) -> StdResult<bool> {
+    Ok(CONSUMED.may_load(storage)?.unwrap_or(false))
+}
+
+/// errors if the ownable has been consumed. a consumed ownable
+/// doesn't accept any further actions
+pub fn assert_not_consumed(storage: &dyn Storage) -> Result<(), OwnableError> {
+    if is_consumed(storage)? {
+        return Err(OwnableError::ConsumeError {
+            val: "ownable is consumed".into(),
+        });
+    }
+    Ok(())
+}
+
+/// makes the ownable consumable in units, starting with the given balance.
+/// the balance must be greater than zero
+pub fn init_balance(storage: &mut dyn Storage, balance: Uint128) -> Result<(), OwnableError> {
+    if balance.is_zero() {
+        return Err(OwnableError::ConsumeError {
+            val: "balance must be greater than zero".into(),
+        });
+    }
+    Ok(BALANCE.save(storage, &balance)?)
+}
+
+/// returns the units left to consume. an ownable without a balance
+/// counts as a single unit, until it is consumed
+pub fn load_balance(storage: &dyn Storage) -> StdResult<Uint128> {
+    match BALANCE.may_load(storage)? {
+        Some(balance) => Ok(balance),
+        None if is_consumed(storage)? => Ok(Uint128::zero()),
+        None => Ok(Uint128::one()),
+    }
+}
+
+/// marks the ownable as consumed. only the owner can consume an
+/// unlocked ownable. the response carries a consume event with the
+/// issuer and ownable type, which the consumer uses to verify it
+/// accepts this kind of ownable, and the id of the ownable stored with
+/// `init_ownable`. the whole remaining balance is consumed
+pub fn try_consume(deps: DepsMut, env: Env, info: MessageInfo) -> Result<Response, OwnableError> {
+    let balance = load_balance(deps.storage)?;
+    consume(deps, env, info, balance)
+}
+
+/// consumes `amount` units of the balance. the ownable is marked as
+/// consumed once the balance is used up. the consume event carries the
+/// amount, so the consumer can check it
+pub fn try_consume_amount(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, OwnableError> {
+    if amount.is_zero() {
+        return Err(OwnableError::ConsumeError {
+            val: "amount must be greater than zero".into(),
+        });
+    }
+    consume(deps, env, info, amount)
+}
+
+fn consume(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    amount: Uint128,
+) -> Result<Response, OwnableError> {
+    let ownable_info = assert_owner(deps.storage, &info.sender)?;
+    assert_not_locked(deps.storage)?;
+    assert_not_consumed(deps.storage)?;
+
+    // the consumer can't tell events apart without the id
+    let consumable = load_ownable_id(deps.storage, &env)?;
+    if consumable.as_str().is_empty() {
+        return Err(OwnableError::ConsumeError {
+            val: "ownable id is unknown".into(),
+        });
+    }
+
+    // a zero balance stored without init_balance
+    if amount.is_zero() {
+        return Err(OwnableError::ConsumeError {
+            val: "nothing left to consume".into(),
+        });
+    }
+
+    let balance = load_balance(deps.storage)?;
+    let remaining = balance
+        .checked_sub(amount)
+        .map_err(|_| OwnableError::ConsumeError {
+            val: format!("insufficient balance {} to consume {}", balance, amount),
+        })?;
+
+    if BALANCE.exists(deps.storage) {
+        BALANCE.save(deps.storage, &remaining)?;
+    }
+    if remaining.is_zero() {
+        CONSUMED.save(deps.storage, &true)?;
+    }
+
+    let event = OwnableEvent::Consume {
+        consumable,
+        owner: ownable_info.owner,
+        issuer: ownable_info.issuer,
+        ownable_type: ownable_info.ownable_type,
+        amount,
+        remaining,
+    };
+
+    Ok(Response::new()
+        .add_attribute("action", "consume")
+        .add_attribute("remaining", remaining)
+        .add_event(Event::from(event)))
+}
+
+/// handles the GetBalance {} query
+pub fn query_balance(deps: Deps) -> StdResult<BalanceResponse> {
+    Ok(BalanceResponse {
+        balance: load_balance(deps.storage)?,
+    })
+}
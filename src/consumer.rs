@@ -0,0 +1,178 @@
+use cosmwasm_std::{Addr, Deps, MessageInfo, StdResult, Storage, Uint128};
+use cw_storage_plus::Map;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::consumable::{CONSUME_EVENT_TYPE, LEGACY_CONSUME_EVENT_TYPE};
+use crate::ownership::assert_owner;
+use crate::{ExternalEventMsg, OwnableError};
+
+/// allow-list of (issuer, consumable type) pairs this ownable can consume
+pub const CONSUMABLES: Map<(&Addr, &str), bool> = Map::new("consumables");
+
+/// consume events accepted by this ownable, keyed by (consumable id,
+/// remaining balance). each consume lowers the balance of a consumable,
+/// so the pair is unique per event
+pub const ACCEPTED_CONSUME_EVENTS: Map<(&Addr, u128), bool> = Map::new("accepted_consume_events");
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct IsConsumerOfResponse {
+    pub is_consumer_of: bool,
+}
+
+/// consume event of a consumable ownable, as relayed to the consumer
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct ConsumeEvent {
+    /// id of the consumed ownable
+    pub consumable: Addr,
+    pub owner: Addr,
+    pub issuer: Addr,
+    pub consumable_type: String,
+    /// units consumed. events without an amount consumed a single unit
+    pub amount: Uint128,
+    /// units left after consuming
+    pub remaining: Uint128,
+}
+
+impl ConsumeEvent {
+    /// parses the attributes of a consume event. the consumable id and
+    /// remaining balance are required to detect replays
+    pub fn from_external(event: &ExternalEventMsg) -> Result<Self, OwnableError> {
+        if event.event_type != CONSUME_EVENT_TYPE
+            && event.event_type != LEGACY_CONSUME_EVENT_TYPE
+        {
+            return Err(OwnableError::ConsumeError {
+                val: format!("unexpected event type {}", event.event_type),
+            });
+        }
+        let attribute = |key: &str| {
+            event
+                .attributes
+                .get(key)
+                .cloned()
+                .ok_or_else(|| OwnableError::ConsumeError {
+                    val: format!("consume event is missing {}", key),
+                })
+        };
+        let amount = match event.attributes.get("amount") {
+            Some(amount) => amount
+                .parse::<Uint128>()
+                .ok()
+                .filter(|amount| !amount.is_zero())
+                .ok_or_else(|| OwnableError::ConsumeError {
+                    val: format!("invalid consume amount {}", amount),
+                })?,
+            None => Uint128::one(),
+        };
+        let consumable = attribute("consumable")?;
+        if consumable.is_empty() {
+            return Err(OwnableError::ConsumeError {
+                val: "consume event has no consumable id".into(),
+            });
+        }
+        let remaining = attribute("remaining")?;
+        let remaining = remaining
+            .parse::<Uint128>()
+            .map_err(|_| OwnableError::ConsumeError {
+                val: format!("invalid remaining balance {}", remaining),
+            })?;
+        Ok(ConsumeEvent {
+            consumable: Addr::unchecked(consumable),
+            owner: Addr::unchecked(attribute("owner")?),
+            issuer: Addr::unchecked(attribute("issuer")?),
+            consumable_type: attribute("ownable_type")?,
+            amount,
+            remaining,
+        })
+    }
+
+    /// errors unless exactly `amount` units were consumed, for consumers
+    /// that take a fixed number of units
+    pub fn assert_amount(&self, amount: Uint128) -> Result<(), OwnableError> {
+        if self.amount != amount {
+            return Err(OwnableError::ConsumeError {
+                val: format!("expected {} units to be consumed, got {}", amount, self.amount),
+            });
+        }
+        Ok(())
+    }
+}
+
+/// adds a consumable (issuer, type) pair to the allow-list
+pub fn allow_consumable(
+    storage: &mut dyn Storage,
+    issuer: &Addr,
+    consumable_type: &str,
+) -> StdResult<()> {
+    CONSUMABLES.save(storage, (issuer, consumable_type), &true)
+}
+
+/// removes a consumable (issuer, type) pair from the allow-list
+pub fn disallow_consumable(storage: &mut dyn Storage, issuer: &Addr, consumable_type: &str) {
+    CONSUMABLES.remove(storage, (issuer, consumable_type))
+}
+
+/// returns whether this ownable can consume ownables of the given
+/// issuer and type
+pub fn is_consumer_of(
+    storage: &dyn Storage,
+    issuer: &Addr,
+    consumable_type: &str,
+) -> StdResult<bool> {
+    Ok(CONSUMABLES
+        .may_load(storage, (issuer, consumable_type))?
+        .unwrap_or(false))
+}
+
+/// verifies the consume event of a consumable. the sender must own
+/// this ownable as well as the consumed one, and the consumable must
+/// be on the allow-list. the event is recorded, so relaying the same
+/// event again fails. returns the parsed event so the contract can
+/// apply the effect of consuming it
+pub fn verify_consume_event(
+    storage: &mut dyn Storage,
+    info: &MessageInfo,
+    event: &ExternalEventMsg,
+) -> Result<ConsumeEvent, OwnableError> {
+    assert_owner(storage, &info.sender)?;
+
+    let consume_event = ConsumeEvent::from_external(event)?;
+    if consume_event.owner != info.sender {
+        return Err(OwnableError::Unauthorized {
+            val: "consumed ownable has a different owner".into(),
+        });
+    }
+    if !is_consumer_of(
+        storage,
+        &consume_event.issuer,
+        &consume_event.consumable_type,
+    )? {
+        return Err(OwnableError::ConsumeError {
+            val: format!(
+                "cannot consume {} ownables of {}",
+                consume_event.consumable_type, consume_event.issuer
+            ),
+        });
+    }
+
+    let key = (&consume_event.consumable, consume_event.remaining.u128());
+    if ACCEPTED_CONSUME_EVENTS.has(storage, key) {
+        return Err(OwnableError::ConsumeError {
+            val: "consume event was already accepted".into(),
+        });
+    }
+    ACCEPTED_CONSUME_EVENTS.save(storage, key, &true)?;
+
+    Ok(consume_event)
+}
+
+/// handles the IsConsumerOf { issuer, consumable_type } query
+pub fn query_consumer_of(
+    deps: Deps,
+    issuer: Addr,
+    consumable_type: String,
+) -> StdResult<IsConsumerOfResponse> {
+    Ok(IsConsumerOfResponse {
+        is_consumer_of: is_consumer_of(deps.storage, &issuer, &consumable_type)?,
+    })
+}
@@ -0,0 +1,37 @@
+use cosmwasm_std::{Binary, StdResult, to_json_binary};
+use serde::Serialize;
+
+/// result of `dispatch_ownable`, generated by the OwnableExecute and
+/// OwnableQuery derives. the variants added by the ownables macros are
+/// handled by ownable-std, all other variants are handed back to the
+/// contract
+#[derive(Clone, Debug, PartialEq)]
+pub enum Dispatch<R, M> {
+    Handled(R),
+    Custom(M),
+}
+
+impl<R, M> Dispatch<R, M> {
+    /// returns the result of a handled message, or handles a custom message
+    /// with `f`
+    pub fn or_else(self, f: impl FnOnce(M) -> R) -> R {
+        match self {
+            Dispatch::Handled(result) => result,
+            Dispatch::Custom(msg) => f(msg),
+        }
+    }
+
+    /// converts the result of a handled message, e.g. into the error type
+    /// of the contract
+    pub fn map_handled<T>(self, f: impl FnOnce(R) -> T) -> Dispatch<T, M> {
+        match self {
+            Dispatch::Handled(result) => Dispatch::Handled(f(result)),
+            Dispatch::Custom(msg) => Dispatch::Custom(msg),
+        }
+    }
+}
+
+/// serializes the response of a handled query
+pub fn to_query_binary<T: Serialize>(response: StdResult<T>) -> StdResult<Binary> {
+    to_json_binary(&response?)
+}
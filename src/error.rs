@@ -0,0 +1,30 @@
+use cosmwasm_std::StdError;
+use thiserror::Error;
+
+/// errors returned by the standard ownable state modules
+#[derive(Error, Debug, PartialEq)]
+pub enum OwnableError {
+    #[error("{0}")]
+    Std(#[from] StdError),
+
+    #[error("Unauthorized: {val}")]
+    Unauthorized { val: String },
+
+    #[error("Lock error: {val}")]
+    LockError { val: String },
+
+    #[error("Consume error: {val}")]
+    ConsumeError { val: String },
+
+    #[error("Instantiate error: {val}")]
+    InstantiateError { val: String },
+
+    #[error("Sub message error: {val}")]
+    SubMsgError { val: String },
+
+    #[error("Royalty error: {val}")]
+    RoyaltyError { val: String },
+
+    #[error("Approval error: {val}")]
+    ApprovalError { val: String },
+}
@@ -0,0 +1,177 @@
+use cosmwasm_std::{Addr, Event, Response, StdError, StdResult, Uint128};
+
+use crate::{OwnableInfo, OwnableInstantiate};
+
+pub const TRANSFER_EVENT: &str = "ownable.transfer";
+pub const LOCK_EVENT: &str = "ownable.lock";
+pub const UNLOCK_EVENT: &str = "ownable.unlock";
+pub const CONSUME_EVENT: &str = "ownable.consume";
+pub const INSTANTIATE_EVENT: &str = "ownable.instantiate";
+
+/// standard events of the ownable actions, so indexers can follow them
+/// regardless of the contract. converts to an `Event` with fixed
+/// attribute keys. an absent ownable type is an empty attribute
+#[derive(Clone, Debug, PartialEq)]
+pub enum OwnableEvent {
+    Transfer {
+        from: Addr,
+        to: Addr,
+    },
+    /// `nonce` counts the locks of the ownable. the unlock event of the
+    /// lock service references it
+    Lock {
+        owner: Addr,
+        nonce: u64,
+    },
+    Unlock {
+        lock_service: Addr,
+    },
+    /// `consumable` is the id of the consumed ownable. together with the
+    /// `remaining` balance it identifies the event, so consumers can
+    /// reject replays
+    Consume {
+        consumable: Addr,
+        owner: Addr,
+        issuer: Addr,
+        ownable_type: Option<String>,
+        amount: Uint128,
+        remaining: Uint128,
+    },
+    Instantiate {
+        ownable_id: String,
+        package: String,
+        owner: Addr,
+        issuer: Addr,
+        ownable_type: Option<String>,
+    },
+}
+
+impl OwnableEvent {
+    pub fn instantiate(msg: &impl OwnableInstantiate, ownable_info: &OwnableInfo) -> Self {
+        OwnableEvent::Instantiate {
+            ownable_id: msg.ownable_id().to_string(),
+            package: msg.package().to_string(),
+            owner: ownable_info.owner.clone(),
+            issuer: ownable_info.issuer.clone(),
+            ownable_type: ownable_info.ownable_type.clone(),
+        }
+    }
+
+    pub fn event_type(&self) -> &'static str {
+        match self {
+            OwnableEvent::Transfer { .. } => TRANSFER_EVENT,
+            OwnableEvent::Lock { .. } => LOCK_EVENT,
+            OwnableEvent::Unlock { .. } => UNLOCK_EVENT,
+            OwnableEvent::Consume { .. } => CONSUME_EVENT,
+            OwnableEvent::Instantiate { .. } => INSTANTIATE_EVENT,
+        }
+    }
+
+    /// parses a standard ownable event. returns None for other events.
+    /// the "wasm-" prefix added by wasmd is accepted
+    pub fn from_event(event: &Event) -> StdResult<Option<Self>> {
+        let ty = event.ty.strip_prefix("wasm-").unwrap_or(&event.ty);
+        let attribute = |key: &str| {
+            event
+                .attributes
+                .iter()
+                .find(|attribute| attribute.key == key)
+                .map(|attribute| attribute.value.clone())
+                .ok_or_else(|| {
+                    StdError::parse_err("OwnableEvent", format!("{} event is missing {}", ty, key))
+                })
+        };
+        let ownable_type = || attribute("ownable_type").map(|t| Some(t).filter(|t| !t.is_empty()));
+
+        let ownable_event = match ty {
+            TRANSFER_EVENT => OwnableEvent::Transfer {
+                from: Addr::unchecked(attribute("from")?),
+                to: Addr::unchecked(attribute("to")?),
+            },
+            LOCK_EVENT => OwnableEvent::Lock {
+                owner: Addr::unchecked(attribute("owner")?),
+                nonce: attribute("nonce")?
+                    .parse()
+                    .map_err(|e| StdError::parse_err("u64", e))?,
+            },
+            UNLOCK_EVENT => OwnableEvent::Unlock {
+                lock_service: Addr::unchecked(attribute("lock_service")?),
+            },
+            CONSUME_EVENT => OwnableEvent::Consume {
+                consumable: Addr::unchecked(attribute("consumable")?),
+                owner: Addr::unchecked(attribute("owner")?),
+                issuer: Addr::unchecked(attribute("issuer")?),
+                ownable_type: ownable_type()?,
+                amount: attribute("amount")?
+                    .parse()
+                    .map_err(|e| StdError::parse_err("Uint128", e))?,
+                remaining: attribute("remaining")?
+                    .parse()
+                    .map_err(|e| StdError::parse_err("Uint128", e))?,
+            },
+            INSTANTIATE_EVENT => OwnableEvent::Instantiate {
+                ownable_id: attribute("ownable_id")?,
+                package: attribute("package")?,
+                owner: Addr::unchecked(attribute("owner")?),
+                issuer: Addr::unchecked(attribute("issuer")?),
+                ownable_type: ownable_type()?,
+            },
+            _ => return Ok(None),
+        };
+        Ok(Some(ownable_event))
+    }
+}
+
+impl From<OwnableEvent> for Event {
+    fn from(ownable_event: OwnableEvent) -> Self {
+        let event = Event::new(ownable_event.event_type());
+        match ownable_event {
+            OwnableEvent::Transfer { from, to } => {
+                event.add_attribute("from", from).add_attribute("to", to)
+            }
+            OwnableEvent::Lock { owner, nonce } => event
+                .add_attribute("owner", owner)
+                .add_attribute("nonce", nonce.to_string()),
+            OwnableEvent::Unlock { lock_service } => {
+                event.add_attribute("lock_service", lock_service)
+            }
+            OwnableEvent::Consume {
+                consumable,
+                owner,
+                issuer,
+                ownable_type,
+                amount,
+                remaining,
+            } => event
+                .add_attribute("consumable", consumable)
+                .add_attribute("owner", owner)
+                .add_attribute("issuer", issuer)
+                .add_attribute("ownable_type", ownable_type.unwrap_or_default())
+                .add_attribute("amount", amount)
+                .add_attribute("remaining", remaining),
+            OwnableEvent::Instantiate {
+                ownable_id,
+                package,
+                owner,
+                issuer,
+                ownable_type,
+            } => event
+                .add_attribute("ownable_id", ownable_id)
+                .add_attribute("package", package)
+                .add_attribute("owner", owner)
+                .add_attribute("issuer", issuer)
+                .add_attribute("ownable_type", ownable_type.unwrap_or_default()),
+        }
+    }
+}
+
+/// parses the standard ownable events of a response, skipping other events
+pub fn parse_events<T>(response: &Response<T>) -> StdResult<Vec<OwnableEvent>> {
+    let mut ownable_events = vec![];
+    for event in &response.events {
+        if let Some(ownable_event) = OwnableEvent::from_event(event)? {
+            ownable_events.push(ownable_event);
+        }
+    }
+    Ok(ownable_events)
+}
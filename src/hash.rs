@@ -0,0 +1,32 @@
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum HashError {
+    #[error("invalid hex in hash {0}")]
+    InvalidHex(String),
+
+    #[error("hash too short, expected at least {expected} bytes but got {actual}")]
+    TooShort { expected: usize, actual: usize },
+}
+
+/// decodes a hex-encoded hash. an optional 0x prefix and odd length
+/// are allowed
+pub fn decode_hash(hash: &str) -> Result<Vec<u8>, HashError> {
+    let mut s = hash.trim().trim_start_matches("0x").to_string();
+    if s.len() % 2 == 1 {
+        s.insert(0, '0');
+    }
+    hex::decode(&s).map_err(|_| HashError::InvalidHex(hash.to_string()))
+}
+
+/// decodes a hex-encoded hash of at least `min_len` bytes
+pub fn decode_hash_min(hash: &str, min_len: usize) -> Result<Vec<u8>, HashError> {
+    let bytes = decode_hash(hash)?;
+    if bytes.len() < min_len {
+        return Err(HashError::TooShort {
+            expected: min_len,
+            actual: bytes.len(),
+        });
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,77 @@
+use crate::asset::Cid;
+use crate::{NFT, OwnableError};
+
+/// accessors for the fields that #[ownables_instantiate_msg] adds to
+/// InstantiateMsg, so shared helpers can read them generically. the macro
+/// implements this trait for the message
+pub trait OwnableInstantiate {
+    /// the type of the nft field, the `NFT` in scope of the message
+    type Nft: NftInfo;
+
+    fn ownable_id(&self) -> &str;
+    fn package(&self) -> &str;
+    fn nft(&self) -> Option<&Self::Nft>;
+    fn ownable_type(&self) -> Option<&str>;
+    fn network_id(&self) -> u8;
+
+    /// checks that the ownable id is set, the package is a valid cid and
+    /// the nft (if any) is on a valid CAIP-2 network
+    fn validate(&self) -> Result<(), OwnableError> {
+        if self.ownable_id().trim().is_empty() {
+            return Err(instantiate_error("ownable_id is empty"));
+        }
+        Cid::parse(self.package()).map_err(|e| instantiate_error(format!("package: {}", e)))?;
+        if self.ownable_type().is_some_and(|t| t.trim().is_empty()) {
+            return Err(instantiate_error("ownable_type is empty"));
+        }
+
+        if let Some(nft) = self.nft() {
+            if !is_caip2_chain_id(nft.network()) {
+                return Err(instantiate_error(format!(
+                    "nft network {} is not a CAIP-2 chain id",
+                    nft.network()
+                )));
+            }
+            if nft.address().trim().is_empty() {
+                return Err(instantiate_error("nft address is empty"));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// the nft fields checked by `OwnableInstantiate::validate`. contracts
+/// that define their own NFT type implement this to use the macro
+pub trait NftInfo {
+    fn network(&self) -> &str;
+    fn address(&self) -> &str;
+}
+
+impl NftInfo for NFT {
+    fn network(&self) -> &str {
+        &self.network
+    }
+
+    fn address(&self) -> &str {
+        &self.address
+    }
+}
+
+fn instantiate_error(val: impl Into<String>) -> OwnableError {
+    OwnableError::InstantiateError { val: val.into() }
+}
+
+/// checks a CAIP-2 chain id, <namespace>:<reference> e.g. eip155:1
+pub fn is_caip2_chain_id(chain_id: &str) -> bool {
+    let Some((namespace, reference)) = chain_id.split_once(':') else {
+        return false;
+    };
+    (3..=8).contains(&namespace.len())
+        && namespace
+            .chars()
+            .all(|c| c == '-' || c.is_ascii_lowercase() || c.is_ascii_digit())
+        && (1..=32).contains(&reference.len())
+        && reference
+            .chars()
+            .all(|c| c == '-' || c == '_' || c.is_ascii_alphanumeric())
+}
@@ -3,10 +3,42 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use serde_json::to_string;
 use serde_with::serde_as;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::marker::PhantomData;
 use wasm_bindgen::{JsValue, JsError};
 
+use crate::hash::{decode_hash, decode_hash_min};
+
+pub mod approval;
+pub mod asset;
+pub mod color;
+pub mod consumable;
+pub mod consumer;
+pub mod dispatch;
+pub mod error;
+pub mod events;
+pub mod hash;
+pub mod instantiate;
+pub mod locale;
+pub mod lock;
+pub mod nft_metadata;
+pub mod ownership;
+pub mod palette;
+pub mod prng;
+pub mod royalty;
+pub mod submsg;
+pub mod svg;
+pub mod validation;
+pub mod widget;
+
+pub use color::Color;
+pub use dispatch::Dispatch;
+pub use error::OwnableError;
+pub use events::OwnableEvent;
+pub use hash::HashError;
+pub use instantiate::OwnableInstantiate;
+pub use locale::LocalizedText;
+
 const CANONICAL_LENGTH: usize = 54;
 
 pub fn create_env() -> Env {
@@ -57,15 +89,10 @@ pub fn get_random_color(hash: String) -> String {
 /// takes a hex-encoded hash and derives a seemingly-random rgb tuple
 pub fn derive_rgb_values(hash: String) -> (u8, u8, u8) {
     // allow optional 0x and odd length
-    let mut s = hash.trim().trim_start_matches("0x").to_string();
-    if s.len() % 2 == 1 {
-        s.insert(0, '0');
-    }
-
-    match hex::decode(&s) {
+    match decode_hash(&hash) {
         Ok(mut bytes) => {
             bytes.reverse();
-            let r = *bytes.get(0).unwrap_or(&0);
+            let r = *bytes.first().unwrap_or(&0);
             let g = *bytes.get(1).unwrap_or(&0);
             let b = *bytes.get(2).unwrap_or(&0);
             (r, g, b)
@@ -74,6 +101,21 @@ pub fn derive_rgb_values(hash: String) -> (u8, u8, u8) {
     }
 }
 
+/// like get_random_color, but errors on an invalid or too short hash
+/// instead of falling back to black
+pub fn try_get_random_color(hash: &str) -> Result<String, HashError> {
+    let (red, green, blue) = try_derive_rgb_values(hash)?;
+    Ok(rgb_hex(red, green, blue))
+}
+
+/// like derive_rgb_values, but errors on an invalid hash or one shorter
+/// than three bytes instead of padding with zeros
+pub fn try_derive_rgb_values(hash: &str) -> Result<(u8, u8, u8), HashError> {
+    let mut bytes = decode_hash_min(hash, 3)?;
+    bytes.reverse();
+    Ok((bytes[0], bytes[1], bytes[2]))
+}
+
 /// takes three u8 values representing rgb values (0-255)f
 /// and returns a hex string
 pub fn rgb_hex(r: u8, g: u8, b: u8) -> String {
@@ -271,10 +313,112 @@ pub struct Metadata {
     pub external_url: Option<String>,
     pub description: Option<String>,
     pub name: Option<String>,
-    // pub attributes: Option<Vec<Trait>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<Trait>>,
     pub background_color: Option<String>,
     pub animation_url: Option<String>,
     pub youtube_url: Option<String>,
+    // translations of name and description, keyed by BCP-47 language tag.
+    // see Metadata::localized
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub localizations: Option<BTreeMap<String, LocalizedText>>,
+    // fields of the ERC-721 / ERC-1155 metadata json that have no
+    // counterpart here, kept so conversions are lossless
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// an ERC-721 metadata attribute, as displayed by marketplaces like OpenSea
+/// e.g. {"display_type": "boost_number", "trait_type": "Aqua Power", "value": 40}.
+/// OpenSea also accepts attributes without trait_type, e.g. {"value": 5}
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+pub struct Trait {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<DisplayType>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub trait_type: Option<String>,
+    pub value: TraitValue,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<serde_json::Number>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, PartialEq, Eq, JsonSchema, Debug)]
+#[serde(rename_all = "snake_case")]
+pub enum DisplayType {
+    Number,
+    BoostNumber,
+    BoostPercentage,
+    // value is a unix timestamp in seconds
+    Date,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+#[serde(untagged)]
+pub enum TraitValue {
+    Number(serde_json::Number),
+    String(String),
+    Bool(bool),
+}
+
+impl Trait {
+    /// a plain text trait, e.g. {"trait_type": "Base", "value": "Starfish"}
+    pub fn string(trait_type: impl Into<String>, value: impl Into<String>) -> Self {
+        Trait {
+            display_type: None,
+            trait_type: Some(trait_type.into()),
+            value: TraitValue::String(value.into()),
+            max_value: None,
+        }
+    }
+
+    /// a yes/no trait, e.g. {"trait_type": "Shiny", "value": true}
+    pub fn boolean(trait_type: impl Into<String>, value: bool) -> Self {
+        Trait {
+            display_type: None,
+            trait_type: Some(trait_type.into()),
+            value: TraitValue::Bool(value),
+            max_value: None,
+        }
+    }
+
+    /// a numeric trait, displayed as a number
+    pub fn number(trait_type: impl Into<String>, value: impl Into<serde_json::Number>) -> Self {
+        Trait::numeric(DisplayType::Number, trait_type, value)
+    }
+
+    /// a numeric trait, displayed as a boost
+    pub fn boost_number(trait_type: impl Into<String>, value: impl Into<serde_json::Number>) -> Self {
+        Trait::numeric(DisplayType::BoostNumber, trait_type, value)
+    }
+
+    /// a numeric trait, displayed as a boost percentage
+    pub fn boost_percentage(trait_type: impl Into<String>, value: impl Into<serde_json::Number>) -> Self {
+        Trait::numeric(DisplayType::BoostPercentage, trait_type, value)
+    }
+
+    /// a date trait, stored as a unix timestamp in seconds
+    pub fn date(trait_type: impl Into<String>, time: Timestamp) -> Self {
+        Trait::numeric(DisplayType::Date, trait_type, time.seconds())
+    }
+
+    fn numeric(
+        display_type: DisplayType,
+        trait_type: impl Into<String>,
+        value: impl Into<serde_json::Number>,
+    ) -> Self {
+        Trait {
+            display_type: Some(display_type),
+            trait_type: Some(trait_type.into()),
+            value: TraitValue::Number(value.into()),
+            max_value: None,
+        }
+    }
+
+    /// sets the maximum of a numeric trait
+    pub fn with_max_value(mut self, max_value: impl Into<serde_json::Number>) -> Self {
+        self.max_value = Some(max_value.into());
+        self
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
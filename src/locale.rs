@@ -0,0 +1,75 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::Metadata;
+
+/// translations of the metadata fields for a single language
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+pub struct LocalizedText {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+}
+
+impl Metadata {
+    /// adds the translations for a BCP-47 language tag, e.g. "de" or "pt-BR"
+    pub fn with_localization(mut self, lang: impl Into<String>, text: LocalizedText) -> Self {
+        self.localizations
+            .get_or_insert_with(BTreeMap::new)
+            .insert(lang.into(), text);
+        self
+    }
+
+    /// returns the metadata with name and description in the requested
+    /// language. each field falls back along the language tag chain, e.g.
+    /// "zh-Hant-TW" -> "zh-Hant" -> "zh", and finally to the default field
+    pub fn localized(&self, lang: &str) -> Metadata {
+        let mut metadata = self.clone();
+        let Some(localizations) = &self.localizations else {
+            return metadata;
+        };
+
+        let chain = fallback_chain(lang);
+        let lookup = |field: fn(&LocalizedText) -> &Option<String>| {
+            chain.iter().find_map(|tag| {
+                localizations
+                    .iter()
+                    .find(|(key, _)| normalize(key) == *tag)
+                    .and_then(|(_, text)| field(text).clone())
+            })
+        };
+
+        if let Some(name) = lookup(|text| &text.name) {
+            metadata.name = Some(name);
+        }
+        if let Some(description) = lookup(|text| &text.description) {
+            metadata.description = Some(description);
+        }
+        metadata
+    }
+}
+
+// language tags are case insensitive and sometimes written with underscores
+fn normalize(tag: &str) -> String {
+    tag.trim().replace('_', "-").to_ascii_lowercase()
+}
+
+// RFC 4647 lookup: drop subtags from the end, along with any single
+// character subtag (extension or private use singleton) left dangling
+fn fallback_chain(lang: &str) -> Vec<String> {
+    let normalized = normalize(lang);
+    let mut subtags: Vec<&str> = normalized.split('-').filter(|s| !s.is_empty()).collect();
+
+    let mut chain = vec![];
+    while !subtags.is_empty() {
+        chain.push(subtags.join("-"));
+        subtags.pop();
+        while subtags.last().is_some_and(|subtag| subtag.len() == 1) {
+            subtags.pop();
+        }
+    }
+    chain
+}
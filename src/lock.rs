@@ -0,0 +1,152 @@
+use cosmwasm_std::{Deps, DepsMut, MessageInfo, Response, StdResult, Storage};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::consumable::assert_not_consumed;
+use crate::ownership::{assert_owner, NFT_ITEM};
+use crate::{ExternalEventMsg, OwnableError, OwnableEvent};
+
+pub const LOCKED: Item<bool> = Item::new("is_locked");
+
+/// number of times the ownable was locked. an unlock event must carry the
+/// nonce of the current lock, so it can't unlock the ownable again later
+pub const LOCK_NONCE: Item<u64> = Item::new("lock_nonce");
+
+/// event type the lock service emits when the bridged nft is released
+pub const UNLOCK_EVENT_TYPE: &str = "unlock";
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct IsLockedResponse {
+    pub is_locked: bool,
+}
+
+/// returns whether the ownable is locked. an ownable that was never
+/// locked has no lock state stored and is considered unlocked
+pub fn is_locked(storage: &dyn Storage) -> StdResult<bool> {
+    Ok(LOCKED.may_load(storage)?.unwrap_or(false))
+}
+
+/// errors if the ownable is locked
+pub fn assert_not_locked(storage: &dyn Storage) -> Result<(), OwnableError> {
+    if is_locked(storage)? {
+        return Err(OwnableError::LockError {
+            val: "ownable is locked".into(),
+        });
+    }
+    Ok(())
+}
+
+/// locks the ownable. only the owner is allowed to lock, and a
+/// consumed ownable can't be locked. the lock event has the nonce the
+/// unlock event must reference
+pub fn try_lock(deps: DepsMut, info: MessageInfo) -> Result<Response, OwnableError> {
+    assert_owner(deps.storage, &info.sender)?;
+    assert_not_locked(deps.storage)?;
+    assert_not_consumed(deps.storage)?;
+
+    let nonce = LOCK_NONCE.may_load(deps.storage)?.unwrap_or_default() + 1;
+    LOCKED.save(deps.storage, &true)?;
+    LOCK_NONCE.save(deps.storage, &nonce)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "lock")
+        .add_attribute("owner", info.sender.clone())
+        .add_event(
+            OwnableEvent::Lock {
+                owner: info.sender,
+                nonce,
+            }
+            .into(),
+        ))
+}
+
+/// locks the ownable like `try_lock`, adding the reason of a
+/// Lock { reason } message to the response
+pub fn try_lock_with_reason(
+    deps: DepsMut,
+    info: MessageInfo,
+    reason: Option<String>,
+) -> Result<Response, OwnableError> {
+    let response = try_lock(deps, info)?;
+    Ok(match reason {
+        Some(reason) => response.add_attribute("reason", reason),
+        None => response,
+    })
+}
+
+/// unlocks the ownable using an unlock event of the nft it is bridged to.
+/// the event must be relayed by the nft's lock service, on the nft's
+/// network, and reference the nft contract, token id and the nonce of the
+/// current lock
+pub fn try_unlock_with_event(
+    deps: DepsMut,
+    info: MessageInfo,
+    event: ExternalEventMsg,
+) -> Result<Response, OwnableError> {
+    let nft = NFT_ITEM.may_load(deps.storage)?.ok_or_else(|| OwnableError::LockError {
+        val: "ownable is not bridged to an nft".into(),
+    })?;
+    let lock_service = nft.lock_service.as_deref().ok_or_else(|| OwnableError::LockError {
+        val: "nft has no lock service".into(),
+    })?;
+
+    if !info.sender.as_str().eq_ignore_ascii_case(lock_service) {
+        return Err(OwnableError::Unauthorized {
+            val: "event not sent by the lock service".into(),
+        });
+    }
+    if event.event_type != UNLOCK_EVENT_TYPE {
+        return Err(OwnableError::LockError {
+            val: format!("unexpected event type {}", event.event_type),
+        });
+    }
+    if event.network.as_deref() != Some(nft.network.as_str()) {
+        return Err(OwnableError::LockError {
+            val: "network mismatch".into(),
+        });
+    }
+
+    let contract = event.attributes.get("contract").map(String::as_str).unwrap_or_default();
+    if !contract.eq_ignore_ascii_case(&nft.address) {
+        return Err(OwnableError::LockError {
+            val: "nft contract mismatch".into(),
+        });
+    }
+    let token_id = event.attributes.get("token_id").map(String::as_str).unwrap_or_default();
+    if token_id != nft.id.to_string() {
+        return Err(OwnableError::LockError {
+            val: "nft token id mismatch".into(),
+        });
+    }
+
+    if !is_locked(deps.storage)? {
+        return Err(OwnableError::LockError {
+            val: "ownable is not locked".into(),
+        });
+    }
+    let nonce = event.attributes.get("nonce").map(String::as_str).unwrap_or_default();
+    if nonce != LOCK_NONCE.load(deps.storage)?.to_string() {
+        return Err(OwnableError::LockError {
+            val: "unlock event is not for the current lock".into(),
+        });
+    }
+    LOCKED.save(deps.storage, &false)?;
+
+    Ok(Response::new()
+        .add_attribute("action", "unlock")
+        .add_attribute("lock_service", info.sender.clone())
+        .add_event(
+            OwnableEvent::Unlock {
+                lock_service: info.sender,
+            }
+            .into(),
+        ))
+}
+
+/// handles the IsLocked {} query
+pub fn query_locked(deps: Deps) -> StdResult<IsLockedResponse> {
+    Ok(IsLockedResponse {
+        is_locked: is_locked(deps.storage)?,
+    })
+}
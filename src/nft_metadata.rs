@@ -0,0 +1,367 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{StdError, StdResult, Uint128};
+use schemars::JsonSchema;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{DisplayType, LocalizedText, Metadata, Trait, TraitValue};
+
+/// ERC-721 metadata json, as served from the token uri of a bridged nft.
+/// fields that have no counterpart in `Metadata` are kept in `extra`
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+pub struct Erc721Metadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_data: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<Trait>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub youtube_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+/// ERC-1155 metadata json. uris may contain the `{id}` placeholder, which
+/// is substituted with the token id by `substitute_id`. besides the fields
+/// of the ERC-1155 metadata schema, the ERC-721 fields read by marketplaces
+/// are supported
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+pub struct Erc1155Metadata {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub decimals: Option<u8>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub properties: Option<BTreeMap<String, Value>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub image_data: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub external_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub attributes: Option<Vec<Trait>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub background_color: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub animation_url: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub youtube_url: Option<String>,
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, Value>,
+}
+
+// extension of github.com/CosmWasm/cw-nfts/blob/main/contracts/cw721-metadata-onchain
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug, Default)]
+pub struct Cw721Metadata {
+    pub image: Option<String>,
+    pub image_data: Option<String>,
+    pub external_url: Option<String>,
+    pub description: Option<String>,
+    pub name: Option<String>,
+    pub attributes: Option<Vec<Cw721Trait>>,
+    pub background_color: Option<String>,
+    pub animation_url: Option<String>,
+    pub youtube_url: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, JsonSchema, Debug)]
+pub struct Cw721Trait {
+    pub display_type: Option<String>,
+    pub trait_type: String,
+    pub value: String,
+}
+
+// key of Metadata::localizations in the json of the other standards.
+// not "localization", which ERC-1155 uses for per-locale metadata uris
+const LOCALIZATIONS_KEY: &str = "localizations";
+
+// ERC-1155 fields kept in Metadata::extra
+const DECIMALS_KEY: &str = "decimals";
+const PROPERTIES_KEY: &str = "properties";
+
+// the extra fields of the other standards: the unknown fields of the
+// metadata, plus its localizations
+fn to_extra(
+    mut extra: BTreeMap<String, Value>,
+    localizations: Option<BTreeMap<String, LocalizedText>>,
+) -> BTreeMap<String, Value> {
+    if let Some(localizations) = localizations.and_then(|l| serde_json::to_value(l).ok()) {
+        extra.insert(LOCALIZATIONS_KEY.to_string(), localizations);
+    }
+    extra
+}
+
+// splits the localizations off the extra fields of the other standards.
+// localizations that don't parse are kept as an unknown field
+fn from_extra(
+    mut extra: BTreeMap<String, Value>,
+) -> (Option<BTreeMap<String, LocalizedText>>, BTreeMap<String, Value>) {
+    let localizations = extra
+        .get(LOCALIZATIONS_KEY)
+        .and_then(|localizations| serde_json::from_value(localizations.clone()).ok());
+    if localizations.is_some() {
+        extra.remove(LOCALIZATIONS_KEY);
+    }
+    (localizations, extra)
+}
+
+// removes an extra field if it has the type of the standard's field
+fn take_extra<T: DeserializeOwned>(extra: &mut BTreeMap<String, Value>, key: &str) -> Option<T> {
+    let value = serde_json::from_value(extra.get(key)?.clone()).ok()?;
+    extra.remove(key);
+    Some(value)
+}
+
+/// formats a token id the way ERC-1155 substitutes it for `{id}`:
+/// lowercase hex, zero padded to 64 characters, without 0x prefix
+pub fn erc1155_id(id: Uint128) -> String {
+    format!("{:064x}", id.u128())
+}
+
+/// replaces the `{id}` placeholder in an ERC-1155 uri
+pub fn erc1155_uri(uri: &str, id: Uint128) -> String {
+    uri.replace("{id}", &erc1155_id(id))
+}
+
+impl Metadata {
+    pub fn from_erc721_json(json: &str) -> StdResult<Metadata> {
+        let erc721: Erc721Metadata =
+            serde_json::from_str(json).map_err(|e| StdError::parse_err("Erc721Metadata", e))?;
+        Ok(erc721.into())
+    }
+
+    pub fn to_erc721_json(&self) -> StdResult<String> {
+        serde_json::to_string(&Erc721Metadata::from(self.clone()))
+            .map_err(|e| StdError::serialize_err("Erc721Metadata", e))
+    }
+
+    /// parses ERC-1155 metadata json of the token with the given id
+    pub fn from_erc1155_json(json: &str, id: Uint128) -> StdResult<Metadata> {
+        let mut erc1155: Erc1155Metadata =
+            serde_json::from_str(json).map_err(|e| StdError::parse_err("Erc1155Metadata", e))?;
+        erc1155.substitute_id(id);
+        Ok(erc1155.into())
+    }
+
+    pub fn to_erc1155_json(&self) -> StdResult<String> {
+        serde_json::to_string(&Erc1155Metadata::from(self.clone()))
+            .map_err(|e| StdError::serialize_err("Erc1155Metadata", e))
+    }
+}
+
+impl Erc1155Metadata {
+    /// substitutes `{id}` in all uris with the token id
+    pub fn substitute_id(&mut self, id: Uint128) {
+        for uri in [
+            &mut self.image,
+            &mut self.external_url,
+            &mut self.animation_url,
+            &mut self.youtube_url,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            *uri = erc1155_uri(uri, id);
+        }
+    }
+}
+
+impl From<Metadata> for Erc721Metadata {
+    fn from(metadata: Metadata) -> Self {
+        Erc721Metadata {
+            name: metadata.name,
+            description: metadata.description,
+            image: metadata.image,
+            image_data: metadata.image_data,
+            external_url: metadata.external_url,
+            attributes: metadata.attributes,
+            background_color: metadata.background_color,
+            animation_url: metadata.animation_url,
+            youtube_url: metadata.youtube_url,
+            extra: to_extra(metadata.extra, metadata.localizations),
+        }
+    }
+}
+
+impl From<Erc721Metadata> for Metadata {
+    fn from(erc721: Erc721Metadata) -> Self {
+        let (localizations, extra) = from_extra(erc721.extra);
+        Metadata {
+            localizations,
+            extra,
+            image: erc721.image,
+            image_data: erc721.image_data,
+            external_url: erc721.external_url,
+            description: erc721.description,
+            name: erc721.name,
+            attributes: erc721.attributes,
+            background_color: erc721.background_color,
+            animation_url: erc721.animation_url,
+            youtube_url: erc721.youtube_url,
+        }
+    }
+}
+
+/// decimals and properties are taken from the extra fields of the metadata
+impl From<Metadata> for Erc1155Metadata {
+    fn from(metadata: Metadata) -> Self {
+        let mut extra = to_extra(metadata.extra, metadata.localizations);
+        Erc1155Metadata {
+            decimals: take_extra(&mut extra, DECIMALS_KEY),
+            properties: take_extra(&mut extra, PROPERTIES_KEY),
+            name: metadata.name,
+            description: metadata.description,
+            image: metadata.image,
+            image_data: metadata.image_data,
+            external_url: metadata.external_url,
+            attributes: metadata.attributes,
+            background_color: metadata.background_color,
+            animation_url: metadata.animation_url,
+            youtube_url: metadata.youtube_url,
+            extra,
+        }
+    }
+}
+
+/// decimals and properties are kept in the extra fields of the metadata
+impl From<Erc1155Metadata> for Metadata {
+    fn from(erc1155: Erc1155Metadata) -> Self {
+        let (localizations, mut extra) = from_extra(erc1155.extra);
+        if let Some(decimals) = erc1155.decimals {
+            extra.insert(DECIMALS_KEY.to_string(), decimals.into());
+        }
+        if let Some(properties) = erc1155.properties {
+            extra.insert(PROPERTIES_KEY.to_string(), Value::Object(properties.into_iter().collect()));
+        }
+        Metadata {
+            localizations,
+            extra,
+            image: erc1155.image,
+            image_data: erc1155.image_data,
+            external_url: erc1155.external_url,
+            description: erc1155.description,
+            name: erc1155.name,
+            attributes: erc1155.attributes,
+            background_color: erc1155.background_color,
+            animation_url: erc1155.animation_url,
+            youtube_url: erc1155.youtube_url,
+        }
+    }
+}
+
+/// cw721 trait values are strings. numbers are formatted as json numbers,
+/// booleans as "true" / "false", and the display type is kept as its snake
+/// case name. a missing trait_type becomes an empty string. `max_value`
+/// can't be represented in cw721 and is dropped
+impl From<Trait> for Cw721Trait {
+    fn from(attribute: Trait) -> Self {
+        let display_type =
+            attribute
+                .display_type
+                .map(|display_type| match serde_json::to_value(display_type) {
+                    Ok(Value::String(name)) => name,
+                    _ => unreachable!("display types serialize to strings"),
+                });
+        let value = match attribute.value {
+            TraitValue::Number(number) => number.to_string(),
+            TraitValue::String(value) => value,
+            TraitValue::Bool(value) => value.to_string(),
+        };
+        Cw721Trait {
+            display_type,
+            trait_type: attribute.trait_type.unwrap_or_default(),
+            value,
+        }
+    }
+}
+
+/// traits with a numeric display type get a numeric value, other traits
+/// keep their string value. an empty trait_type is left out
+impl TryFrom<Cw721Trait> for Trait {
+    type Error = StdError;
+
+    fn try_from(attribute: Cw721Trait) -> StdResult<Self> {
+        let display_type: Option<DisplayType> = attribute
+            .display_type
+            .map(|name| {
+                serde_json::from_value(Value::String(name))
+                    .map_err(|e| StdError::parse_err("DisplayType", e))
+            })
+            .transpose()?;
+        let value = match display_type {
+            Some(_) => TraitValue::Number(
+                attribute
+                    .value
+                    .parse()
+                    .map_err(|e| StdError::parse_err("serde_json::Number", e))?,
+            ),
+            None => TraitValue::String(attribute.value),
+        };
+        Ok(Trait {
+            display_type,
+            trait_type: Some(attribute.trait_type).filter(|t| !t.is_empty()),
+            value,
+            max_value: None,
+        })
+    }
+}
+
+/// cw721 has no place for localizations and extra fields, only the
+/// default name and description are kept
+impl From<Metadata> for Cw721Metadata {
+    fn from(metadata: Metadata) -> Self {
+        Cw721Metadata {
+            image: metadata.image,
+            image_data: metadata.image_data,
+            external_url: metadata.external_url,
+            description: metadata.description,
+            name: metadata.name,
+            attributes: metadata
+                .attributes
+                .map(|attributes| attributes.into_iter().map(Cw721Trait::from).collect()),
+            background_color: metadata.background_color,
+            animation_url: metadata.animation_url,
+            youtube_url: metadata.youtube_url,
+        }
+    }
+}
+
+impl TryFrom<Cw721Metadata> for Metadata {
+    type Error = StdError;
+
+    fn try_from(cw721: Cw721Metadata) -> StdResult<Self> {
+        let attributes = cw721
+            .attributes
+            .map(|attributes| attributes.into_iter().map(Trait::try_from).collect())
+            .transpose()?;
+        Ok(Metadata {
+            image: cw721.image,
+            image_data: cw721.image_data,
+            external_url: cw721.external_url,
+            description: cw721.description,
+            name: cw721.name,
+            attributes,
+            background_color: cw721.background_color,
+            animation_url: cw721.animation_url,
+            youtube_url: cw721.youtube_url,
+            localizations: None,
+            extra: BTreeMap::new(),
+        })
+    }
+}
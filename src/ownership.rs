@@ -0,0 +1,128 @@
+use cosmwasm_std::{Addr, Deps, DepsMut, Env, MessageInfo, Response, StdResult, Storage};
+use cw_storage_plus::Item;
+
+use crate::approval::{assert_approved, clear_approvals, clear_operators};
+use crate::consumable::assert_not_consumed;
+use crate::lock::assert_not_locked;
+use crate::{InfoResponse, NFT, OwnableError, OwnableEvent, OwnableInfo, OwnableInstantiate};
+
+pub const OWNABLE_INFO: Item<OwnableInfo> = Item::new("ownable_info");
+pub const NFT_ITEM: Item<NFT> = Item::new("nft");
+
+/// id of the ownable from InstantiateMsg. the host doesn't set
+/// `env.contract.address`, so events that identify the ownable use this
+pub const OWNABLE_ID: Item<String> = Item::new("ownable_id");
+
+/// stores the ownership state of a newly instantiated ownable,
+/// along with the nft it is bridged to (if any)
+pub fn init_ownership(
+    storage: &mut dyn Storage,
+    ownable_info: &OwnableInfo,
+    nft: Option<&NFT>,
+) -> StdResult<()> {
+    OWNABLE_INFO.save(storage, ownable_info)?;
+    if let Some(nft) = nft {
+        NFT_ITEM.save(storage, nft)?;
+    }
+    Ok(())
+}
+
+/// stores the ownership state of an ownable instantiated with `msg`,
+/// like `init_ownership`, along with the ownable id of the message
+pub fn init_ownable(
+    storage: &mut dyn Storage,
+    msg: &impl OwnableInstantiate<Nft = NFT>,
+    ownable_info: &OwnableInfo,
+) -> StdResult<()> {
+    init_ownable_id(storage, msg.ownable_id())?;
+    init_ownership(storage, ownable_info, msg.nft())
+}
+
+/// stores the id of the ownable, for contracts that call `init_ownership`
+/// themselves
+pub fn init_ownable_id(storage: &mut dyn Storage, ownable_id: &str) -> StdResult<()> {
+    OWNABLE_ID.save(storage, &ownable_id.to_string())
+}
+
+/// returns the id of the ownable stored at instantiate, or else
+/// `env.contract.address`, which is only set on chain and when running
+/// as a sub message
+pub fn load_ownable_id(storage: &dyn Storage, env: &Env) -> StdResult<Addr> {
+    Ok(match OWNABLE_ID.may_load(storage)? {
+        Some(ownable_id) => Addr::unchecked(ownable_id),
+        None => env.contract.address.clone(),
+    })
+}
+
+/// returns the current owner of the ownable
+pub fn load_owner(storage: &dyn Storage) -> StdResult<Addr> {
+    Ok(OWNABLE_INFO.load(storage)?.owner)
+}
+
+/// errors unless the sender is the current owner. returns the
+/// loaded ownership state so callers don't need to load it again
+pub fn assert_owner(storage: &dyn Storage, sender: &Addr) -> Result<OwnableInfo, OwnableError> {
+    let ownable_info = OWNABLE_INFO.load(storage)?;
+    if ownable_info.owner != *sender {
+        return Err(OwnableError::Unauthorized {
+            val: "not the owner".into(),
+        });
+    }
+    Ok(ownable_info)
+}
+
+/// transfers the ownable to a new owner. the current owner, an approved
+/// spender or an operator of the owner is allowed to transfer, and only
+/// while the ownable is unlocked and not consumed. the approvals of
+/// spenders are cleared
+pub fn try_transfer(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    to: Addr,
+) -> Result<Response, OwnableError> {
+    let mut ownable_info = assert_approved(deps.storage, &env, &info.sender)?;
+    assert_not_locked(deps.storage)?;
+    assert_not_consumed(deps.storage)?;
+    let to = deps.api.addr_validate(to.as_str())?;
+
+    let from = ownable_info.owner;
+    ownable_info.owner = to.clone();
+    OWNABLE_INFO.save(deps.storage, &ownable_info)?;
+    clear_approvals(deps.storage);
+    clear_operators(deps.storage, &from);
+
+    Ok(Response::new()
+        .add_attribute("action", "transfer")
+        .add_attribute("from", from.clone())
+        .add_attribute("to", to.clone())
+        .add_event(OwnableEvent::Transfer { from, to }.into()))
+}
+
+/// transfers the ownable like `try_transfer`, adding the memo of a
+/// Transfer { to, memo } message to the response
+pub fn try_transfer_with_memo(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    to: Addr,
+    memo: Option<String>,
+) -> Result<Response, OwnableError> {
+    let response = try_transfer(deps, env, info, to)?;
+    Ok(match memo {
+        Some(memo) => response.add_attribute("memo", memo),
+        None => response,
+    })
+}
+
+/// handles the GetInfo {} query
+pub fn query_info(deps: Deps) -> StdResult<InfoResponse> {
+    let ownable_info = OWNABLE_INFO.load(deps.storage)?;
+    let nft = NFT_ITEM.may_load(deps.storage)?;
+    Ok(InfoResponse {
+        owner: ownable_info.owner,
+        issuer: ownable_info.issuer,
+        nft,
+        ownable_type: ownable_info.ownable_type,
+    })
+}
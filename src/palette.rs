@@ -0,0 +1,292 @@
+use thiserror::Error;
+
+use crate::hash::{HashError, decode_hash_min};
+use crate::rgb_hex;
+
+// bytes of the hash needed to derive the base color
+const BASE_BYTES: usize = 4;
+
+/// a color in HSL space. hue in degrees [0, 360), saturation and
+/// lightness in [0, 1]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    pub hue: f64,
+    pub saturation: f64,
+    pub lightness: f64,
+}
+
+impl Hsl {
+    pub fn new(hue: f64, saturation: f64, lightness: f64) -> Self {
+        Hsl {
+            hue: hue.rem_euclid(360.0),
+            saturation: saturation.clamp(0.0, 1.0),
+            lightness: lightness.clamp(0.0, 1.0),
+        }
+    }
+
+    pub fn from_rgb(r: u8, g: u8, b: u8) -> Self {
+        let (r, g, b) = (r as f64 / 255.0, g as f64 / 255.0, b as f64 / 255.0);
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let lightness = (max + min) / 2.0;
+        let delta = max - min;
+        if delta == 0.0 {
+            return Hsl::new(0.0, 0.0, lightness);
+        }
+
+        let saturation = delta / (1.0 - (2.0 * lightness - 1.0).abs());
+        let hue = if max == r {
+            60.0 * ((g - b) / delta).rem_euclid(6.0)
+        } else if max == g {
+            60.0 * ((b - r) / delta + 2.0)
+        } else {
+            60.0 * ((r - g) / delta + 4.0)
+        };
+        Hsl::new(hue, saturation, lightness)
+    }
+
+    pub fn to_rgb(&self) -> (u8, u8, u8) {
+        let chroma = (1.0 - (2.0 * self.lightness - 1.0).abs()) * self.saturation;
+        let sector = self.hue / 60.0;
+        let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+        let (r, g, b) = match sector as u32 {
+            0 => (chroma, x, 0.0),
+            1 => (x, chroma, 0.0),
+            2 => (0.0, chroma, x),
+            3 => (0.0, x, chroma),
+            4 => (x, 0.0, chroma),
+            _ => (chroma, 0.0, x),
+        };
+        let m = self.lightness - chroma / 2.0;
+        let channel = |c: f64| ((c + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        (channel(r), channel(g), channel(b))
+    }
+
+    /// e.g. #1E90FF
+    pub fn to_hex(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        rgb_hex(r, g, b)
+    }
+
+    /// e.g. rgb(30, 144, 255)
+    pub fn to_rgb_css(&self) -> String {
+        let (r, g, b) = self.to_rgb();
+        format!("rgb({}, {}, {})", r, g, b)
+    }
+
+    /// e.g. hsl(210, 100%, 56%)
+    pub fn to_hsl_css(&self) -> String {
+        format!(
+            "hsl({}, {}%, {}%)",
+            self.hue.round() as u32 % 360,
+            (self.saturation * 100.0).round(),
+            (self.lightness * 100.0).round()
+        )
+    }
+}
+
+/// WCAG relative luminance of an rgb color
+pub fn relative_luminance(r: u8, g: u8, b: u8) -> f64 {
+    let linear = |c: u8| {
+        let c = c as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * linear(r) + 0.7152 * linear(g) + 0.0722 * linear(b)
+}
+
+/// WCAG contrast ratio between two rgb colors, from 1 to 21
+pub fn contrast_ratio(a: (u8, u8, u8), b: (u8, u8, u8)) -> f64 {
+    let la = relative_luminance(a.0, a.1, a.2);
+    let lb = relative_luminance(b.0, b.1, b.2);
+    (la.max(lb) + 0.05) / (la.min(lb) + 0.05)
+}
+
+/// returned by `Palette::with_contrast` when the contrast ratio can't be
+/// met. holds the palette adjusted as far as possible
+#[derive(Error, Debug, PartialEq, Clone)]
+#[error(
+    "contrast ratio {min_ratio} not reached, colors {below_ratio:?} are below the ratio and colors {not_distinct:?} are not distinct"
+)]
+pub struct ContrastError {
+    pub min_ratio: f64,
+    /// indexes of the colors with a lower contrast ratio
+    pub below_ratio: Vec<usize>,
+    /// indexes of the colors that collapsed onto an earlier color
+    pub not_distinct: Vec<usize>,
+    pub palette: Palette,
+}
+
+/// color scheme used to spread the hues of a palette
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Harmony {
+    /// the base hue and its opposite
+    Complementary,
+    /// neighbouring hues, 30 degrees apart
+    Analogous,
+    /// three hues, 120 degrees apart
+    Triadic,
+}
+
+impl Harmony {
+    fn hue_offsets(&self) -> &'static [f64] {
+        match self {
+            Harmony::Complementary => &[0.0, 180.0],
+            Harmony::Analogous => &[0.0, 30.0, -30.0],
+            Harmony::Triadic => &[0.0, 120.0, 240.0],
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Palette {
+    pub colors: Vec<Hsl>,
+}
+
+impl Palette {
+    /// derives `count` harmonious colors from a hex-encoded hash. the same
+    /// hash always yields the same palette
+    pub fn derive(hash: &str, harmony: Harmony, count: usize) -> Result<Palette, HashError> {
+        let bytes = decode_hash_min(hash, BASE_BYTES)?;
+        let byte = |i: usize| bytes[i % bytes.len()] as f64 / 255.0;
+
+        let base_hue = u16::from_be_bytes([bytes[0], bytes[1]]) as f64 % 360.0;
+        let base_saturation = 0.45 + byte(2) * 0.4;
+        let base_lightness = 0.35 + byte(3) * 0.3;
+
+        let offsets = harmony.hue_offsets();
+        let colors = (0..count)
+            .map(|i| {
+                // colors that reuse a hue get a lighter or darker shade
+                let round = (i / offsets.len()) as f64;
+                let shade = if round as u32 % 2 == 1 { round } else { -round } * 0.12;
+                let jitter = (byte(BASE_BYTES + i) - 0.5) * 10.0;
+                Hsl::new(
+                    base_hue + offsets[i % offsets.len()] + jitter,
+                    base_saturation,
+                    (base_lightness + shade).clamp(0.1, 0.9),
+                )
+            })
+            .collect();
+
+        Ok(Palette { colors })
+    }
+
+    /// adjusts the lightness of each color so it has at least the given
+    /// contrast ratio against the background, keeping hue and saturation
+    /// (4.5 is the WCAG AA level for text). errors with the indexes of the
+    /// colors that can't reach the ratio, or that can't be kept distinct
+    /// from an earlier color at that ratio
+    pub fn with_contrast(
+        mut self,
+        background: (u8, u8, u8),
+        min_ratio: f64,
+    ) -> Result<Palette, ContrastError> {
+        let background_luminance = relative_luminance(background.0, background.1, background.2);
+        let mut seen: Vec<(u8, u8, u8)> = vec![];
+        let mut below_ratio = vec![];
+        let mut not_distinct = vec![];
+
+        for (i, color) in self.colors.iter_mut().enumerate() {
+            let (mut adjusted, reached) = ensure_contrast(*color, background, min_ratio);
+            if !reached {
+                below_ratio.push(i);
+            }
+
+            // shades can collapse onto the same color. move them further
+            // away from the background, which only increases contrast, or
+            // else closer to it as long as the ratio holds
+            let (r, g, b) = adjusted.to_rgb();
+            let away = if relative_luminance(r, g, b) < background_luminance {
+                -0.06
+            } else {
+                0.06
+            };
+            for step in [away, -away] {
+                let mut candidate = adjusted;
+                while seen.contains(&candidate.to_rgb())
+                    && (0.0..=1.0).contains(&(candidate.lightness + step))
+                {
+                    candidate.lightness += step;
+                }
+                if !seen.contains(&candidate.to_rgb())
+                    && (!reached || contrast_ratio(candidate.to_rgb(), background) >= min_ratio)
+                {
+                    adjusted = candidate;
+                    break;
+                }
+            }
+            if reached && seen.contains(&adjusted.to_rgb()) {
+                not_distinct.push(i);
+            }
+
+            seen.push(adjusted.to_rgb());
+            *color = adjusted;
+        }
+
+        if below_ratio.is_empty() && not_distinct.is_empty() {
+            Ok(self)
+        } else {
+            Err(ContrastError {
+                min_ratio,
+                below_ratio,
+                not_distinct,
+                palette: self,
+            })
+        }
+    }
+
+    pub fn to_hex(&self) -> Vec<String> {
+        self.colors.iter().map(Hsl::to_hex).collect()
+    }
+
+    pub fn to_rgb_css(&self) -> Vec<String> {
+        self.colors.iter().map(Hsl::to_rgb_css).collect()
+    }
+
+    pub fn to_hsl_css(&self) -> Vec<String> {
+        self.colors.iter().map(Hsl::to_hsl_css).collect()
+    }
+}
+
+// returns the adjusted color and whether it reaches the ratio
+fn ensure_contrast(color: Hsl, background: (u8, u8, u8), min_ratio: f64) -> (Hsl, bool) {
+    let contrast = |lightness: f64| {
+        contrast_ratio(
+            Hsl::new(color.hue, color.saturation, lightness).to_rgb(),
+            background,
+        )
+    };
+    if contrast(color.lightness) >= min_ratio {
+        return (color, true);
+    }
+
+    // step darker and lighter, taking the smallest change that suffices
+    let mut darker = None;
+    let mut lighter = None;
+    for step in 1..=100 {
+        let delta = step as f64 / 100.0;
+        let down = color.lightness - delta;
+        let up = color.lightness + delta;
+        if darker.is_none() && down >= 0.0 && contrast(down) >= min_ratio {
+            darker = Some(down);
+        }
+        if lighter.is_none() && up <= 1.0 && contrast(up) >= min_ratio {
+            lighter = Some(up);
+        }
+        if darker.is_some() || lighter.is_some() {
+            break;
+        }
+    }
+
+    // when the ratio can't be reached, get as close as possible
+    let (lightness, reached) = match darker.or(lighter) {
+        Some(lightness) => (lightness, true),
+        None if contrast(0.0) >= contrast(1.0) => (0.0, false),
+        None => (1.0, false),
+    };
+    (Hsl::new(color.hue, color.saturation, lightness), reached)
+}
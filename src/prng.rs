@@ -0,0 +1,125 @@
+use rand_chacha::ChaCha20Rng;
+use rand_chacha::rand_core::{Error, SeedableRng};
+use sha2::{Digest, Sha256};
+
+use crate::hash::{HashError, decode_hash_min};
+
+pub use rand_chacha::rand_core::RngCore;
+
+/// minimum number of hash bytes accepted as seed
+pub const MIN_SEED_BYTES: usize = 16;
+
+/// deterministic random number generator for generative ownables.
+///
+/// the ChaCha20 stream for a seed is the same on every platform, and the
+/// helpers below don't depend on the `rand` crate's sampling algorithms,
+/// which may change between versions. a trait derived in the wasm contract
+/// can therefore be reproduced by native verification tools
+#[derive(Clone, Debug)]
+pub struct OwnableRng {
+    rng: ChaCha20Rng,
+}
+
+impl OwnableRng {
+    pub fn from_seed(seed: [u8; 32]) -> Self {
+        OwnableRng {
+            rng: ChaCha20Rng::from_seed(seed),
+        }
+    }
+
+    /// seeds the generator with the sha256 of a hex-encoded hash
+    pub fn from_hash(hash: &str) -> Result<Self, HashError> {
+        let bytes = decode_hash_min(hash, MIN_SEED_BYTES)?;
+        Ok(OwnableRng::from_seed(Sha256::digest(bytes).into()))
+    }
+
+    /// seeds the generator from an ownable id and a hex-encoded event hash,
+    /// so the same event yields different values for different ownables
+    pub fn from_ownable(ownable_id: &str, event_hash: &str) -> Result<Self, HashError> {
+        let bytes = decode_hash_min(event_hash, MIN_SEED_BYTES)?;
+        let seed = Sha256::new()
+            .chain_update(ownable_id.as_bytes())
+            .chain_update([0u8])
+            .chain_update(bytes)
+            .finalize();
+        Ok(OwnableRng::from_seed(seed.into()))
+    }
+
+    /// returns a uniformly distributed value in [low, high).
+    /// panics if the range is empty
+    pub fn gen_range(&mut self, low: u64, high: u64) -> u64 {
+        assert!(low < high, "empty range {}..{}", low, high);
+        let span = high - low;
+        // reject the values that would bias the modulo
+        let limit = u64::MAX - u64::MAX % span;
+        loop {
+            let value = self.rng.next_u64();
+            if value < limit {
+                return low + value % span;
+            }
+        }
+    }
+
+    /// returns a uniformly distributed value in [0, 1)
+    pub fn gen_f64(&mut self) -> f64 {
+        (self.rng.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// returns true with a probability of numerator / denominator.
+    /// panics if the denominator is zero
+    pub fn gen_ratio(&mut self, numerator: u64, denominator: u64) -> bool {
+        self.gen_range(0, denominator) < numerator
+    }
+
+    /// picks a random item, or None if there are no items
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> Option<&'a T> {
+        if items.is_empty() {
+            return None;
+        }
+        items.get(self.gen_range(0, items.len() as u64) as usize)
+    }
+
+    /// picks an item with a probability proportional to its weight, or None
+    /// if the weights add up to zero
+    pub fn choose_weighted<'a, T>(&mut self, items: &'a [(T, u32)]) -> Option<&'a T> {
+        let total: u64 = items.iter().map(|(_, weight)| *weight as u64).sum();
+        if total == 0 {
+            return None;
+        }
+
+        let mut target = self.gen_range(0, total);
+        for (item, weight) in items {
+            if target < *weight as u64 {
+                return Some(item);
+            }
+            target -= *weight as u64;
+        }
+        unreachable!("target is below the total weight")
+    }
+
+    /// shuffles the items in place (Fisher-Yates)
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.gen_range(0, i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+}
+
+impl RngCore for OwnableRng {
+    fn next_u32(&mut self) -> u32 {
+        self.rng.next_u32()
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        self.rng.next_u64()
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        self.rng.fill_bytes(dest)
+    }
+
+    fn try_fill_bytes(&mut self, dest: &mut [u8]) -> Result<(), Error> {
+        self.rng.try_fill_bytes(dest)
+    }
+}
@@ -0,0 +1,122 @@
+use cosmwasm_std::{Addr, Deps, StdResult, Storage, Uint128};
+use cw_storage_plus::Item;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::OwnableError;
+
+/// 100% in basis points
+pub const MAX_BASIS_POINTS: u16 = 10_000;
+
+pub const ROYALTY: Item<Royalty> = Item::new("royalty");
+
+/// creator fee paid to `recipient` when the ownable is sold, in basis
+/// points (1/100 of a percent) of the sale price
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq, JsonSchema)]
+pub struct Royalty {
+    pub recipient: Addr,
+    pub basis_points: u16,
+}
+
+/// response of the RoyaltyInfo { sale_price } query, like cw2981. the
+/// address is empty and the amount zero if the ownable has no royalty
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct RoyaltyInfoResponse {
+    pub address: String,
+    pub royalty_amount: Uint128,
+}
+
+/// return value of EIP-2981 `royaltyInfo(tokenId, salePrice)`
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct Eip2981RoyaltyInfo {
+    pub receiver: String,
+    pub royalty_amount: Uint128,
+}
+
+impl Royalty {
+    pub fn new(recipient: Addr, basis_points: u16) -> Result<Self, OwnableError> {
+        let royalty = Royalty {
+            recipient,
+            basis_points,
+        };
+        royalty.validate()?;
+        Ok(royalty)
+    }
+
+    /// errors if the royalty is more than 100%
+    pub fn validate(&self) -> Result<(), OwnableError> {
+        if self.basis_points > MAX_BASIS_POINTS {
+            return Err(OwnableError::RoyaltyError {
+                val: format!(
+                    "{} basis points is more than {}",
+                    self.basis_points, MAX_BASIS_POINTS
+                ),
+            });
+        }
+        Ok(())
+    }
+
+    /// royalty for the sale price, rounded down
+    pub fn amount(&self, sale_price: Uint128) -> Uint128 {
+        sale_price.multiply_ratio(self.basis_points, MAX_BASIS_POINTS)
+    }
+
+    /// royalty info as returned by the EIP-2981 contract of a bridged nft
+    pub fn to_eip2981(&self, sale_price: Uint128) -> Eip2981RoyaltyInfo {
+        Eip2981RoyaltyInfo {
+            receiver: self.recipient.to_string(),
+            royalty_amount: self.amount(sale_price),
+        }
+    }
+
+    /// derives the royalty from the EIP-2981 royalty info of a bridged nft,
+    /// queried with `sale_price`. EIP-2981 contracts may return any amount
+    /// for a price, so use a sale price that is a multiple of 10000 to
+    /// avoid rounding, e.g. 10^18
+    pub fn from_eip2981(
+        info: &Eip2981RoyaltyInfo,
+        sale_price: Uint128,
+    ) -> Result<Self, OwnableError> {
+        if sale_price.is_zero() {
+            return Err(OwnableError::RoyaltyError {
+                val: "sale price is zero".into(),
+            });
+        }
+        let basis_points = info
+            .royalty_amount
+            .multiply_ratio(MAX_BASIS_POINTS, sale_price);
+        if basis_points > Uint128::from(MAX_BASIS_POINTS) {
+            return Err(OwnableError::RoyaltyError {
+                val: "royalty amount exceeds the sale price".into(),
+            });
+        }
+        Royalty::new(Addr::unchecked(&info.receiver), basis_points.u128() as u16)
+    }
+}
+
+/// stores the royalty of the ownable, e.g. on instantiate
+pub fn init_royalty(storage: &mut dyn Storage, royalty: &Royalty) -> Result<(), OwnableError> {
+    royalty.validate()?;
+    ROYALTY.save(storage, royalty)?;
+    Ok(())
+}
+
+/// returns the royalty of the ownable, if it has one
+pub fn load_royalty(storage: &dyn Storage) -> StdResult<Option<Royalty>> {
+    ROYALTY.may_load(storage)
+}
+
+/// handles the RoyaltyInfo { sale_price } query
+pub fn query_royalty_info(deps: Deps, sale_price: Uint128) -> StdResult<RoyaltyInfoResponse> {
+    Ok(match load_royalty(deps.storage)? {
+        Some(royalty) => RoyaltyInfoResponse {
+            address: royalty.recipient.to_string(),
+            royalty_amount: royalty.amount(sale_price),
+        },
+        None => RoyaltyInfoResponse {
+            address: String::new(),
+            royalty_amount: Uint128::zero(),
+        },
+    })
+}
@@ -0,0 +1,219 @@
+use std::fmt::Display;
+
+use cosmwasm_std::{
+    Addr, Binary, CosmosMsg, DepsMut, Env, Event, MessageInfo, Reply, ReplyOn, Response, StdResult,
+    SubMsg, SubMsgResponse, SubMsgResult, WasmMsg, from_json, to_json_binary,
+};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+
+use crate::{IdbStateDump, OwnableError, load_owned_deps};
+
+/// message from one ownable to another. it's carried in a `Response` as
+/// a `SubMsg` with a `WasmMsg::Execute`, where the contract address is
+/// the id of the target ownable
+#[derive(Clone, Debug, PartialEq)]
+pub struct OwnableSubMsg {
+    /// id the reply is sent with, to match it to the message
+    pub id: u64,
+    pub ownable_id: String,
+    /// json encoded ExecuteMsg of the target
+    pub msg: Binary,
+    pub reply_on: ReplyOn,
+}
+
+impl OwnableSubMsg {
+    pub fn new(
+        id: u64,
+        ownable_id: impl Into<String>,
+        msg: &impl Serialize,
+        reply_on: ReplyOn,
+    ) -> StdResult<Self> {
+        Ok(OwnableSubMsg {
+            id,
+            ownable_id: ownable_id.into(),
+            msg: to_json_binary(msg)?,
+            reply_on,
+        })
+    }
+
+    /// parses a sub message added to a `Response`. only executing
+    /// another ownable, without funds, is supported
+    pub fn from_submsg<T>(sub_msg: &SubMsg<T>) -> Result<Self, OwnableError> {
+        match &sub_msg.msg {
+            CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr,
+                msg,
+                funds,
+            }) if funds.is_empty() => Ok(OwnableSubMsg {
+                id: sub_msg.id,
+                ownable_id: contract_addr.clone(),
+                msg: msg.clone(),
+                reply_on: sub_msg.reply_on.clone(),
+            }),
+            _ => Err(OwnableError::SubMsgError {
+                val: "only executing an ownable without funds is supported".into(),
+            }),
+        }
+    }
+
+    /// parses all sub messages of a response
+    pub fn from_response<T>(response: &Response<T>) -> Result<Vec<Self>, OwnableError> {
+        response.messages.iter().map(Self::from_submsg).collect()
+    }
+}
+
+impl From<OwnableSubMsg> for SubMsg {
+    fn from(msg: OwnableSubMsg) -> Self {
+        SubMsg {
+            id: msg.id,
+            msg: CosmosMsg::Wasm(WasmMsg::Execute {
+                contract_addr: msg.ownable_id,
+                msg: msg.msg,
+                funds: vec![],
+            }),
+            gas_limit: None,
+            reply_on: msg.reply_on,
+        }
+    }
+}
+
+/// the ownable that sent a sub message, along with the reply entrypoint
+/// of its contract
+pub struct SubMsgSender<F> {
+    pub ownable_id: String,
+    pub state: IdbStateDump,
+    /// account that executed the sending ownable. ownables act on behalf
+    /// of this account, so the target sees it as the sender, e.g. a user
+    /// that owns both a consumer and a consumable
+    pub info: MessageInfo,
+    /// reply entrypoint of the sender, called depending on `reply_on`
+    pub reply: F,
+}
+
+/// outcome of running a sub message on the target ownable and the reply
+/// on the sending ownable
+#[derive(Clone, Debug, PartialEq)]
+pub struct SubMsgExecution {
+    /// state of the target after the message. unchanged if it failed
+    pub state: IdbStateDump,
+    /// response of the target. sub messages of the target are not run
+    /// by the executor, the host should run them the same way
+    pub response: Option<Response>,
+    /// reply passed to the sender, depending on `reply_on`
+    pub reply: Option<Reply>,
+    /// state of the sender after its reply. unchanged without a reply
+    pub sender_state: IdbStateDump,
+    /// response of the reply entrypoint of the sender
+    pub reply_response: Option<Response>,
+}
+
+/// runs a sub message on the target ownable, loaded from its state dump,
+/// using the execute entrypoint of the target contract, then passes the
+/// reply to the reply entrypoint of the sender.
+///
+/// the states are only returned when everything succeeded. if the
+/// message fails and the sender doesn't handle the error in its reply,
+/// or the reply fails, an error is returned and the host keeps the
+/// previous state of both ownables, so the flow is atomic
+pub fn execute_submsg<M, E, F, R>(
+    sub_msg: &OwnableSubMsg,
+    sender: SubMsgSender<F>,
+    state: &IdbStateDump,
+    env: Env,
+    execute: impl FnOnce(DepsMut, Env, MessageInfo, M) -> Result<Response, E>,
+) -> Result<SubMsgExecution, OwnableError>
+where
+    M: DeserializeOwned,
+    E: Display,
+    F: FnOnce(DepsMut, Env, Reply) -> Result<Response, R>,
+    R: Display,
+{
+    let mut deps = load_owned_deps(Some(state.clone()));
+    let mut target_env = env.clone();
+    target_env.contract.address = Addr::unchecked(&sub_msg.ownable_id);
+
+    let result = from_json::<M>(&sub_msg.msg)
+        .map_err(|e| e.to_string())
+        .and_then(|msg| {
+            execute(deps.as_mut(), target_env, sender.info, msg).map_err(|e| e.to_string())
+        });
+
+    let reply_on_success = matches!(sub_msg.reply_on, ReplyOn::Always | ReplyOn::Success);
+    let reply_on_error = matches!(sub_msg.reply_on, ReplyOn::Always | ReplyOn::Error);
+
+    let (state, response, reply) = match result {
+        Ok(response) => {
+            let reply = reply_on_success.then(|| Reply {
+                id: sub_msg.id,
+                result: SubMsgResult::Ok(SubMsgResponse {
+                    events: response_events(&sub_msg.ownable_id, &response),
+                    data: response.data.clone(),
+                }),
+            });
+            (IdbStateDump::from(deps.storage), Some(response), reply)
+        }
+        Err(error) if reply_on_error => (
+            state.clone(),
+            None,
+            Some(Reply {
+                id: sub_msg.id,
+                result: SubMsgResult::Err(error),
+            }),
+        ),
+        Err(error) => {
+            return Err(OwnableError::SubMsgError {
+                val: format!("{} failed: {}", sub_msg.ownable_id, error),
+            });
+        }
+    };
+
+    let Some(reply) = reply else {
+        return Ok(SubMsgExecution {
+            state,
+            response,
+            reply: None,
+            sender_state: sender.state,
+            reply_response: None,
+        });
+    };
+
+    let mut sender_deps = load_owned_deps(Some(sender.state));
+    let mut sender_env = env;
+    sender_env.contract.address = Addr::unchecked(&sender.ownable_id);
+    let reply_response =
+        (sender.reply)(sender_deps.as_mut(), sender_env, reply.clone()).map_err(|e| {
+            OwnableError::SubMsgError {
+                val: format!("reply of {} failed: {}", sender.ownable_id, e),
+            }
+        })?;
+
+    Ok(SubMsgExecution {
+        state,
+        response,
+        reply: Some(reply),
+        sender_state: IdbStateDump::from(sender_deps.storage),
+        reply_response: Some(reply_response),
+    })
+}
+
+// events of a response as reported by wasmd: the attributes in a "wasm"
+// event and custom events prefixed with "wasm-", each with the contract address
+fn response_events(ownable_id: &str, response: &Response) -> Vec<Event> {
+    let mut events = vec![];
+    if !response.attributes.is_empty() {
+        events.push(
+            Event::new("wasm")
+                .add_attribute("_contract_address", ownable_id)
+                .add_attributes(response.attributes.clone()),
+        );
+    }
+    for event in &response.events {
+        events.push(
+            Event::new(format!("wasm-{}", event.ty))
+                .add_attribute("_contract_address", ownable_id)
+                .add_attributes(event.attributes.clone()),
+        );
+    }
+    events
+}
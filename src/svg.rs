@@ -0,0 +1,291 @@
+use std::fmt::{self, Display, Write};
+
+use cosmwasm_std::Binary;
+
+use crate::validation::{UNSAFE_ELEMENTS, is_unsafe_attribute};
+
+const SVG_NAMESPACE: &str = "http://www.w3.org/2000/svg";
+
+/// an svg document, composed of layers drawn in order. rendering the same
+/// document always produces the same string: attributes keep the order in
+/// which they were set and no whitespace is added
+#[derive(Clone, Debug, PartialEq)]
+pub struct Svg {
+    width: f64,
+    height: f64,
+    view_box: Option<(f64, f64, f64, f64)>,
+    defs: Vec<Element>,
+    children: Vec<Node>,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Node {
+    Element(Element),
+    // escaped when rendered
+    Text(String),
+}
+
+/// an svg element. element and attribute names are static, values and
+/// text content are escaped when rendered. elements and attributes that
+/// `sanitize_svg` removes, like scripts and event handlers, are never
+/// rendered
+#[derive(Clone, Debug, PartialEq)]
+pub struct Element {
+    name: &'static str,
+    attributes: Vec<(&'static str, String)>,
+    children: Vec<Node>,
+}
+
+impl Svg {
+    pub fn new(width: f64, height: f64) -> Self {
+        Svg {
+            width,
+            height,
+            view_box: None,
+            defs: vec![],
+            children: vec![],
+        }
+    }
+
+    pub fn view_box(mut self, min_x: f64, min_y: f64, width: f64, height: f64) -> Self {
+        self.view_box = Some((min_x, min_y, width, height));
+        self
+    }
+
+    /// adds a definition, like a gradient, that elements can reference
+    /// by id, e.g. `.fill("url(#sky)")`
+    pub fn def(mut self, element: Element) -> Self {
+        self.defs.push(element);
+        self
+    }
+
+    /// adds a layer on top of the ones added before
+    pub fn layer(mut self, element: Element) -> Self {
+        self.children.push(Node::Element(element));
+        self
+    }
+
+    pub fn render(&self) -> String {
+        let mut out = String::new();
+        let mut root = Element::new("svg")
+            .attr("xmlns", SVG_NAMESPACE)
+            .attr("width", num(self.width))
+            .attr("height", num(self.height));
+        if let Some((x, y, w, h)) = self.view_box {
+            root = root.attr(
+                "viewBox",
+                format!("{} {} {} {}", num(x), num(y), num(w), num(h)),
+            );
+        }
+        if !self.defs.is_empty() {
+            root.children.push(Node::Element(Element {
+                name: "defs",
+                attributes: vec![],
+                children: self.defs.iter().cloned().map(Node::Element).collect(),
+            }));
+        }
+        root.children.extend(self.children.iter().cloned());
+        root.write(&mut out);
+        out
+    }
+
+    /// renders the document as a base64 data uri, which can be used for
+    /// `Metadata::image` or an <img> src
+    pub fn to_data_uri(&self) -> String {
+        format!(
+            "data:image/svg+xml;base64,{}",
+            Binary::from(self.render().as_bytes()).to_base64()
+        )
+    }
+}
+
+impl Display for Svg {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
+
+impl Element {
+    pub fn new(name: &'static str) -> Self {
+        Element {
+            name,
+            attributes: vec![],
+            children: vec![],
+        }
+    }
+
+    /// sets an attribute, replacing an earlier value of the same attribute
+    pub fn attr(mut self, name: &'static str, value: impl Display) -> Self {
+        let value = value.to_string();
+        match self.attributes.iter_mut().find(|(n, _)| *n == name) {
+            Some((_, v)) => *v = value,
+            None => self.attributes.push((name, value)),
+        }
+        self
+    }
+
+    pub fn child(mut self, element: Element) -> Self {
+        self.children.push(Node::Element(element));
+        self
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.children.push(Node::Text(text.into()));
+        self
+    }
+
+    pub fn id(self, id: impl Display) -> Self {
+        self.attr("id", id)
+    }
+
+    pub fn fill(self, color: impl Display) -> Self {
+        self.attr("fill", color)
+    }
+
+    pub fn stroke(self, color: impl Display, width: f64) -> Self {
+        self.attr("stroke", color).attr("stroke-width", num(width))
+    }
+
+    pub fn opacity(self, opacity: f64) -> Self {
+        self.attr("opacity", num(opacity))
+    }
+
+    pub fn transform(self, transform: impl Display) -> Self {
+        self.attr("transform", transform)
+    }
+
+    fn write(&self, out: &mut String) {
+        if UNSAFE_ELEMENTS.contains(&self.name.to_ascii_lowercase().as_str()) {
+            return;
+        }
+
+        out.push('<');
+        out.push_str(self.name);
+        for (name, value) in &self.attributes {
+            if is_unsafe_attribute(&name.to_ascii_lowercase(), value) {
+                continue;
+            }
+            let _ = write!(out, " {}=\"{}\"", name, escape(value, true));
+        }
+        if self.children.is_empty() {
+            out.push_str("/>");
+            return;
+        }
+
+        out.push('>');
+        for child in &self.children {
+            match child {
+                Node::Element(element) => element.write(out),
+                Node::Text(text) => out.push_str(&escape(text, false)),
+            }
+        }
+        let _ = write!(out, "</{}>", self.name);
+    }
+}
+
+/// a group of elements, drawn as a single layer
+pub fn layer(id: impl Display) -> Element {
+    Element::new("g").id(id)
+}
+
+pub fn rect(x: f64, y: f64, width: f64, height: f64) -> Element {
+    Element::new("rect")
+        .attr("x", num(x))
+        .attr("y", num(y))
+        .attr("width", num(width))
+        .attr("height", num(height))
+}
+
+pub fn circle(cx: f64, cy: f64, r: f64) -> Element {
+    Element::new("circle")
+        .attr("cx", num(cx))
+        .attr("cy", num(cy))
+        .attr("r", num(r))
+}
+
+pub fn ellipse(cx: f64, cy: f64, rx: f64, ry: f64) -> Element {
+    Element::new("ellipse")
+        .attr("cx", num(cx))
+        .attr("cy", num(cy))
+        .attr("rx", num(rx))
+        .attr("ry", num(ry))
+}
+
+pub fn line(x1: f64, y1: f64, x2: f64, y2: f64) -> Element {
+    Element::new("line")
+        .attr("x1", num(x1))
+        .attr("y1", num(y1))
+        .attr("x2", num(x2))
+        .attr("y2", num(y2))
+}
+
+pub fn polygon(points: &[(f64, f64)]) -> Element {
+    let points: Vec<String> = points
+        .iter()
+        .map(|(x, y)| format!("{},{}", num(*x), num(*y)))
+        .collect();
+    Element::new("polygon").attr("points", points.join(" "))
+}
+
+/// a path with the given path data, e.g. "M0 0 L10 10"
+pub fn path(d: impl Display) -> Element {
+    Element::new("path").attr("d", d)
+}
+
+/// a text element. the content is escaped, so it can't add markup
+pub fn text(x: f64, y: f64, content: impl Into<String>) -> Element {
+    Element::new("text")
+        .attr("x", num(x))
+        .attr("y", num(y))
+        .text(content)
+}
+
+/// a linear gradient from left to right, with stops as (offset in [0, 1], color)
+pub fn linear_gradient(id: impl Display, stops: &[(f64, &dyn Display)]) -> Element {
+    gradient_stops(Element::new("linearGradient").id(id), stops)
+}
+
+/// a radial gradient from the center outwards, with stops as (offset in [0, 1], color)
+pub fn radial_gradient(id: impl Display, stops: &[(f64, &dyn Display)]) -> Element {
+    gradient_stops(Element::new("radialGradient").id(id), stops)
+}
+
+fn gradient_stops(mut gradient: Element, stops: &[(f64, &dyn Display)]) -> Element {
+    for (offset, color) in stops {
+        gradient = gradient.child(
+            Element::new("stop")
+                .attr(
+                    "offset",
+                    format!("{}%", num(offset.clamp(0.0, 1.0) * 100.0)),
+                )
+                .attr("stop-color", color),
+        );
+    }
+    gradient
+}
+
+// formats a number without trailing zeros. non-finite numbers would make
+// the svg invalid and are rendered as 0
+fn num(value: f64) -> String {
+    if value.is_finite() {
+        // normalizes -0 to 0
+        (value + 0.0).to_string()
+    } else {
+        "0".to_string()
+    }
+}
+
+fn escape(s: &str, attribute: bool) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' if attribute => out.push_str("&quot;"),
+            '\'' if attribute => out.push_str("&#39;"),
+            _ => out.push(c),
+        }
+    }
+    out
+}
@@ -0,0 +1,435 @@
+use thiserror::Error;
+
+use crate::{Metadata, TraitValue};
+
+pub const MAX_NAME_LENGTH: usize = 256;
+pub const MAX_DESCRIPTION_LENGTH: usize = 10_000;
+pub const MAX_URL_LENGTH: usize = 2_048;
+pub const MAX_DATA_URI_LENGTH: usize = 100_000;
+pub const MAX_IMAGE_DATA_LENGTH: usize = 100_000;
+pub const MAX_TRAIT_LENGTH: usize = 256;
+
+// elements that can run code or embed html, removed including their content.
+// animation elements can set any attribute, e.g. href to a javascript: url,
+// and style can load urls and inject markup once the svg is inlined in html
+pub(crate) const UNSAFE_ELEMENTS: [&str; 10] = [
+    "script",
+    "foreignobject",
+    "animate",
+    "set",
+    "animatetransform",
+    "iframe",
+    "frame",
+    "embed",
+    "object",
+    "style",
+];
+
+// attributes holding a url that is loaded or navigated to
+const URL_ATTRIBUTES: [&str; 5] = ["href", "src", "data", "action", "formaction"];
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum MetadataError {
+    #[error("{field}: unsupported url scheme in {value}")]
+    InvalidUrlScheme { field: &'static str, value: String },
+
+    #[error("{field}: malformed url {value}")]
+    InvalidUrl { field: &'static str, value: String },
+
+    #[error("background_color must be a six digit hex color without #, got {value}")]
+    InvalidBackgroundColor { value: String },
+
+    #[error("{field} is {len} characters long, the maximum is {max}")]
+    TooLong {
+        field: &'static str,
+        len: usize,
+        max: usize,
+    },
+
+    #[error("image_data contains scripts, event handlers or other unsafe markup")]
+    UnsafeImageData,
+}
+
+impl Metadata {
+    /// checks the metadata against the ERC-721 / OpenSea metadata standard.
+    /// image_data is unsafe if `sanitize` would change it. returns all
+    /// problems found rather than stopping at the first
+    pub fn validate(&self) -> Result<(), Vec<MetadataError>> {
+        let mut errors = vec![];
+
+        check_length(&mut errors, "name", &self.name, MAX_NAME_LENGTH);
+        check_length(
+            &mut errors,
+            "description",
+            &self.description,
+            MAX_DESCRIPTION_LENGTH,
+        );
+
+        const MEDIA_SCHEMES: &[&str] = &["https", "ipfs", "ar", "data"];
+        check_url(&mut errors, "image", &self.image, MEDIA_SCHEMES);
+        check_url(
+            &mut errors,
+            "animation_url",
+            &self.animation_url,
+            MEDIA_SCHEMES,
+        );
+        check_url(
+            &mut errors,
+            "external_url",
+            &self.external_url,
+            &["https", "ipfs", "ar"],
+        );
+        check_url(&mut errors, "youtube_url", &self.youtube_url, &["https"]);
+
+        if let Some(color) = &self.background_color
+            && (color.len() != 6 || !color.chars().all(|c| c.is_ascii_hexdigit()))
+        {
+            errors.push(MetadataError::InvalidBackgroundColor {
+                value: color.clone(),
+            });
+        }
+
+        if let Some(image_data) = &self.image_data {
+            check_length(
+                &mut errors,
+                "image_data",
+                &self.image_data,
+                MAX_IMAGE_DATA_LENGTH,
+            );
+            if sanitize_svg(image_data) != *image_data {
+                errors.push(MetadataError::UnsafeImageData);
+            }
+        }
+
+        for attribute in self.attributes.iter().flatten() {
+            check_length(
+                &mut errors,
+                "trait_type",
+                &attribute.trait_type,
+                MAX_TRAIT_LENGTH,
+            );
+            if let TraitValue::String(value) = &attribute.value
+                && value.len() > MAX_TRAIT_LENGTH
+            {
+                errors.push(MetadataError::TooLong {
+                    field: "trait value",
+                    len: value.len(),
+                    max: MAX_TRAIT_LENGTH,
+                });
+            }
+        }
+
+        if errors.is_empty() {
+            Ok(())
+        } else {
+            Err(errors)
+        }
+    }
+
+    /// strips scripts and event handlers from the image_data svg
+    pub fn sanitize(&mut self) {
+        if let Some(image_data) = &self.image_data {
+            self.image_data = Some(sanitize_svg(image_data));
+        }
+    }
+}
+
+fn check_length(
+    errors: &mut Vec<MetadataError>,
+    field: &'static str,
+    value: &Option<String>,
+    max: usize,
+) {
+    if let Some(value) = value
+        && value.len() > max
+    {
+        errors.push(MetadataError::TooLong {
+            field,
+            len: value.len(),
+            max,
+        });
+    }
+}
+
+fn check_url(
+    errors: &mut Vec<MetadataError>,
+    field: &'static str,
+    value: &Option<String>,
+    schemes: &[&str],
+) {
+    let Some(url) = value else {
+        return;
+    };
+
+    let Some((scheme, rest)) = url.split_once(':') else {
+        errors.push(MetadataError::InvalidUrl {
+            field,
+            value: url.clone(),
+        });
+        return;
+    };
+    let scheme = scheme.to_ascii_lowercase();
+    if !schemes.contains(&scheme.as_str()) {
+        errors.push(MetadataError::InvalidUrlScheme {
+            field,
+            value: url.clone(),
+        });
+        return;
+    }
+
+    let (max, well_formed) = match scheme.as_str() {
+        // data:[<media type>][;base64],<data>
+        "data" => (MAX_DATA_URI_LENGTH, rest.contains(',')),
+        _ => (
+            MAX_URL_LENGTH,
+            rest.strip_prefix("//").is_some_and(|path| {
+                !path.is_empty() && !path.chars().any(|c| c.is_whitespace() || c.is_control())
+            }),
+        ),
+    };
+    if url.len() > max {
+        errors.push(MetadataError::TooLong {
+            field,
+            len: url.len(),
+            max,
+        });
+    }
+    if !well_formed {
+        errors.push(MetadataError::InvalidUrl {
+            field,
+            value: url.clone(),
+        });
+    }
+}
+
+/// removes script, foreignObject, animation, style and embedding elements
+/// (iframe, embed, object), event handler attributes (onload, onclick,
+/// ...), srcdoc, javascript: urls, comments,
+/// processing instructions and doctypes from an svg document. cdata
+/// sections are replaced by their escaped text. markup that is already
+/// safe is returned unchanged
+pub fn sanitize_svg(svg: &str) -> String {
+    let mut out = String::with_capacity(svg.len());
+    let mut rest = svg;
+
+    while let Some(start) = rest.find('<') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        // html and xml parsers disagree on where these end, e.g. html ends a
+        // comment at "--!>" and a processing instruction at the first ">",
+        // so they're dropped and whatever follows is sanitized as markup.
+        // cdata is text to xml, its markup is escaped for html
+        if let Some(content) = rest.strip_prefix("<![CDATA[") {
+            let end = content.find("]]>").unwrap_or(content.len());
+            escape_text(&mut out, &content[..end]);
+            rest = content.get(end + 3..).unwrap_or("");
+            continue;
+        }
+        let dropped = [("<!--", "-->"), ("<?", "?>"), ("<!", ">")]
+            .into_iter()
+            .find(|(open, _)| rest.starts_with(open));
+        if let Some((open, close)) = dropped {
+            rest = rest[open.len()..]
+                .find(close)
+                .map(|i| &rest[open.len() + i + close.len()..])
+                .unwrap_or("");
+            continue;
+        }
+
+        // drop unterminated tags
+        let Some(tag_end) = find_tag_end(rest) else {
+            rest = "";
+            break;
+        };
+        let tag = &rest[..=tag_end];
+        rest = &rest[tag_end + 1..];
+
+        let closing = tag.starts_with("</");
+        let name_start = if closing { 2 } else { 1 };
+        let name_len = tag[name_start..]
+            .find(|c: char| c.is_whitespace() || c == '/' || c == '>')
+            .unwrap_or(tag.len() - name_start);
+        let name = tag[name_start..name_start + name_len].to_ascii_lowercase();
+
+        if UNSAFE_ELEMENTS.contains(&name.as_str()) {
+            if !closing && !tag.ends_with("/>") {
+                // skip the element content up to and including its closing tag
+                let close = format!("</{}", name);
+                rest = match rest.to_ascii_lowercase().find(&close) {
+                    Some(i) => {
+                        let after = &rest[i..];
+                        after.find('>').map(|end| &after[end + 1..]).unwrap_or("")
+                    }
+                    None => "",
+                };
+            }
+            continue;
+        }
+
+        if closing {
+            out.push_str(tag);
+        } else {
+            sanitize_tag(&mut out, tag, name_start + name_len);
+        }
+    }
+
+    out.push_str(rest);
+    out
+}
+
+// index of the '>' closing the tag at the start of `s`, ignoring quoted values
+fn find_tag_end(s: &str) -> Option<usize> {
+    let mut quote = None;
+    for (i, c) in s.char_indices() {
+        match (quote, c) {
+            (None, '"' | '\'') => quote = Some(c),
+            (Some(q), _) if q == c => quote = None,
+            (None, '>') => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+// copies an opening tag to `out`, leaving out unsafe attributes
+fn sanitize_tag(out: &mut String, tag: &str, attrs_start: usize) {
+    out.push_str(&tag[..attrs_start]);
+    let bytes = tag.as_bytes();
+    let mut i = attrs_start;
+
+    loop {
+        let attr_start = i;
+        // a slash that doesn't close the tag separates attributes like whitespace
+        while i < bytes.len()
+            && (bytes[i].is_ascii_whitespace()
+                || (bytes[i] == b'/' && bytes.get(i + 1) != Some(&b'>')))
+        {
+            i += 1;
+        }
+        if i >= bytes.len() || bytes[i] == b'>' || bytes[i] == b'/' {
+            out.push_str(&tag[attr_start..]);
+            return;
+        }
+
+        let name_start = i;
+        while i < bytes.len() && !bytes[i].is_ascii_whitespace() && !b"=/>".contains(&bytes[i]) {
+            i += 1;
+        }
+        let name = tag[name_start..i].to_ascii_lowercase();
+
+        let mut value = "";
+        let mut j = i;
+        while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+            j += 1;
+        }
+        if j < bytes.len() && bytes[j] == b'=' {
+            j += 1;
+            while j < bytes.len() && bytes[j].is_ascii_whitespace() {
+                j += 1;
+            }
+            let value_start = j;
+            if j < bytes.len() && (bytes[j] == b'"' || bytes[j] == b'\'') {
+                let quote = bytes[j];
+                j += 1;
+                while j < bytes.len() && bytes[j] != quote {
+                    j += 1;
+                }
+                value = &tag[value_start + 1..j];
+                j = (j + 1).min(bytes.len());
+            } else {
+                while j < bytes.len() && !bytes[j].is_ascii_whitespace() && bytes[j] != b'>' {
+                    j += 1;
+                }
+                value = &tag[value_start..j];
+            }
+            i = j;
+        }
+
+        if !is_unsafe_attribute(&name, value) {
+            out.push_str(&tag[attr_start..i]);
+        }
+    }
+}
+
+pub(crate) fn is_unsafe_attribute(name: &str, value: &str) -> bool {
+    // srcdoc is an html document of its own
+    if name.starts_with("on") || name == "srcdoc" {
+        return true;
+    }
+    let local_name = name.rsplit(':').next().unwrap_or(name);
+    if URL_ATTRIBUTES.contains(&local_name) {
+        // browsers decode entities, then ignore whitespace and control
+        // characters in the scheme
+        let scheme: String = decode_entities(value)
+            .chars()
+            .filter(|c| !c.is_whitespace() && !c.is_control())
+            .take(11)
+            .collect();
+        return scheme.eq_ignore_ascii_case("javascript:");
+    }
+    false
+}
+
+// decodes numeric character references, with or without the closing ';',
+// and the named references that can spell out a url scheme
+fn decode_entities(value: &str) -> String {
+    const NAMED: [(&str, char); 8] = [
+        ("colon;", ':'),
+        ("tab;", '\t'),
+        ("newline;", '\n'),
+        ("amp;", '&'),
+        ("lt;", '<'),
+        ("gt;", '>'),
+        ("quot;", '"'),
+        ("apos;", '\''),
+    ];
+
+    let mut out = String::with_capacity(value.len());
+    let mut rest = value;
+    while let Some(start) = rest.find('&') {
+        out.push_str(&rest[..start]);
+        rest = &rest[start + 1..];
+
+        if let Some(number) = rest.strip_prefix('#') {
+            let (digits, radix) = match number.strip_prefix(['x', 'X']) {
+                Some(hex) => (hex, 16),
+                None => (number, 10),
+            };
+            let len = digits
+                .find(|c: char| !c.is_digit(radix))
+                .unwrap_or(digits.len());
+            // out of range references decode to the replacement character
+            let decoded = u32::from_str_radix(&digits[..len], radix)
+                .ok()
+                .map(|code| char::from_u32(code).unwrap_or(char::REPLACEMENT_CHARACTER));
+            if let Some(c) = decoded.filter(|_| len > 0) {
+                out.push(c);
+                let after = &digits[len..];
+                rest = after.strip_prefix(';').unwrap_or(after);
+                continue;
+            }
+        } else if let Some((name, c)) = NAMED.iter().find(|(name, _)| {
+            rest.get(..name.len())
+                .is_some_and(|prefix| prefix.eq_ignore_ascii_case(name))
+        }) {
+            out.push(*c);
+            rest = &rest[name.len()..];
+            continue;
+        }
+        out.push('&');
+    }
+    out.push_str(rest);
+    out
+}
+
+fn escape_text(out: &mut String, text: &str) {
+    for c in text.chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            c => out.push(c),
+        }
+    }
+}
@@ -0,0 +1,94 @@
+use std::collections::BTreeMap;
+
+use cosmwasm_std::{Addr, Deps, StdError, StdResult};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::consumable::is_consumed;
+use crate::lock::is_locked;
+use crate::ownership::OWNABLE_INFO;
+use crate::{Metadata, OwnableInfo};
+
+/// response of the GetWidgetState {} query, rendered by the ownable's
+/// frontend widget
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema, Default)]
+pub struct WidgetState {
+    pub name: Option<String>,
+    pub description: Option<String>,
+    pub image: Option<String>,
+    pub image_data: Option<String>,
+    pub background_color: Option<String>,
+    pub color: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub layers: Vec<WidgetLayer>,
+    pub owner: Option<Addr>,
+    pub issuer: Option<Addr>,
+    pub ownable_type: Option<String>,
+    pub consumed: bool,
+    pub locked: bool,
+    // free-form state specific to the ownable's widget
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub extras: BTreeMap<String, serde_json::Value>,
+}
+
+/// a layer of the widget image, drawn in order
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, JsonSchema)]
+pub struct WidgetLayer {
+    pub name: String,
+    pub image: Option<String>,
+    pub color: Option<String>,
+}
+
+impl WidgetState {
+    /// builds the widget state from the ownable's metadata and ownership
+    pub fn new(metadata: &Metadata, ownable_info: &OwnableInfo) -> Self {
+        WidgetState::from_metadata(metadata).with_ownable_info(ownable_info)
+    }
+
+    /// takes the display fields from the metadata
+    pub fn from_metadata(metadata: &Metadata) -> Self {
+        WidgetState {
+            name: metadata.name.clone(),
+            description: metadata.description.clone(),
+            image: metadata.image.clone(),
+            image_data: metadata.image_data.clone(),
+            background_color: metadata.background_color.clone(),
+            ..WidgetState::default()
+        }
+    }
+
+    pub fn with_ownable_info(mut self, ownable_info: &OwnableInfo) -> Self {
+        self.owner = Some(ownable_info.owner.clone());
+        self.issuer = Some(ownable_info.issuer.clone());
+        self.ownable_type = ownable_info.ownable_type.clone();
+        self
+    }
+
+    pub fn with_color(mut self, color: impl Into<String>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    pub fn with_layer(mut self, layer: WidgetLayer) -> Self {
+        self.layers.push(layer);
+        self
+    }
+
+    /// adds a free-form value to the widget state
+    pub fn with_extra(mut self, key: impl Into<String>, value: &impl Serialize) -> StdResult<Self> {
+        let value = serde_json::to_value(value)
+            .map_err(|e| StdError::serialize_err("serde_json::Value", e))?;
+        self.extras.insert(key.into(), value);
+        Ok(self)
+    }
+}
+
+/// handles the GetWidgetState {} query by combining the metadata with
+/// the stored ownership, lock and consume state
+pub fn query_widget_state(deps: Deps, metadata: &Metadata) -> StdResult<WidgetState> {
+    let ownable_info = OWNABLE_INFO.load(deps.storage)?;
+    let mut widget_state = WidgetState::new(metadata, &ownable_info);
+    widget_state.locked = is_locked(deps.storage)?;
+    widget_state.consumed = is_consumed(deps.storage)?;
+    Ok(widget_state)
+}
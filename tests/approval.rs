@@ -0,0 +1,350 @@
+mod common;
+
+use common::ownable_info;
+use cosmwasm_std::testing::{mock_dependencies, mock_info};
+use cosmwasm_std::{Addr, Timestamp};
+use ownable_std::approval::{
+    Approval, query_approvals, try_approve, try_approve_all, try_revoke, try_revoke_all,
+};
+use ownable_std::ownership::{init_ownership, load_owner, try_transfer};
+use ownable_std::{OwnableError, create_ownable_env};
+
+fn at(seconds: u64) -> cosmwasm_std::Env {
+    create_ownable_env("", Some(Timestamp::from_seconds(seconds)))
+}
+
+fn unauthorized() -> OwnableError {
+    OwnableError::Unauthorized {
+        val: "not the owner or approved".into(),
+    }
+}
+
+#[test]
+fn approved_spender_can_transfer() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+
+    let err = try_transfer(
+        deps.as_mut(),
+        at(0),
+        mock_info("spender", &[]),
+        Addr::unchecked("buyer"),
+    )
+    .unwrap_err();
+    assert_eq!(err, unauthorized());
+
+    // only the owner can approve
+    let err = try_approve(
+        deps.as_mut(),
+        at(0),
+        mock_info("spender", &[]),
+        Addr::unchecked("spender"),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, OwnableError::Unauthorized { .. }));
+
+    try_approve(
+        deps.as_mut(),
+        at(0),
+        mock_info("owner", &[]),
+        Addr::unchecked("spender"),
+        None,
+    )
+    .unwrap();
+    try_transfer(
+        deps.as_mut(),
+        at(0),
+        mock_info("spender", &[]),
+        Addr::unchecked("buyer"),
+    )
+    .unwrap();
+
+    assert_eq!(load_owner(deps.as_ref().storage).unwrap(), "buyer");
+
+    // approvals don't carry over to the new owner
+    assert!(
+        query_approvals(deps.as_ref(), at(0))
+            .unwrap()
+            .approvals
+            .is_empty()
+    );
+    let err = try_transfer(
+        deps.as_mut(),
+        at(0),
+        mock_info("spender", &[]),
+        Addr::unchecked("spender"),
+    )
+    .unwrap_err();
+    assert_eq!(err, unauthorized());
+}
+
+#[test]
+fn approvals_expire() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+
+    let err = try_approve(
+        deps.as_mut(),
+        at(100),
+        mock_info("owner", &[]),
+        Addr::unchecked("spender"),
+        Some(Timestamp::from_seconds(100)),
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        OwnableError::ApprovalError {
+            val: "expiry is in the past".into()
+        }
+    );
+
+    try_approve(
+        deps.as_mut(),
+        at(100),
+        mock_info("owner", &[]),
+        Addr::unchecked("spender"),
+        Some(Timestamp::from_seconds(200)),
+    )
+    .unwrap();
+    assert_eq!(
+        query_approvals(deps.as_ref(), at(199)).unwrap().approvals,
+        vec![Approval {
+            spender: Addr::unchecked("spender"),
+            expires: Some(Timestamp::from_seconds(200)),
+        }]
+    );
+    assert!(
+        query_approvals(deps.as_ref(), at(200))
+            .unwrap()
+            .approvals
+            .is_empty()
+    );
+
+    let err = try_transfer(
+        deps.as_mut(),
+        at(200),
+        mock_info("spender", &[]),
+        Addr::unchecked("buyer"),
+    )
+    .unwrap_err();
+    assert_eq!(err, unauthorized());
+}
+
+#[test]
+fn revoked_spender_cannot_transfer() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+
+    try_approve(
+        deps.as_mut(),
+        at(0),
+        mock_info("owner", &[]),
+        Addr::unchecked("spender"),
+        None,
+    )
+    .unwrap();
+    try_revoke(
+        deps.as_mut(),
+        at(0),
+        mock_info("owner", &[]),
+        Addr::unchecked("spender"),
+    )
+    .unwrap();
+
+    let err = try_transfer(
+        deps.as_mut(),
+        at(0),
+        mock_info("spender", &[]),
+        Addr::unchecked("buyer"),
+    )
+    .unwrap_err();
+    assert_eq!(err, unauthorized());
+}
+
+#[test]
+fn operator_can_transfer_and_approve() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+
+    try_approve_all(
+        deps.as_mut(),
+        at(0),
+        mock_info("owner", &[]),
+        Addr::unchecked("operator"),
+        None,
+    )
+    .unwrap();
+    assert_eq!(
+        query_approvals(deps.as_ref(), at(0)).unwrap().operators,
+        vec![Approval {
+            spender: Addr::unchecked("operator"),
+            expires: None,
+        }]
+    );
+
+    // operators can approve spenders on behalf of the owner
+    try_approve(
+        deps.as_mut(),
+        at(0),
+        mock_info("operator", &[]),
+        Addr::unchecked("spender"),
+        None,
+    )
+    .unwrap();
+
+    try_transfer(
+        deps.as_mut(),
+        at(0),
+        mock_info("operator", &[]),
+        Addr::unchecked("buyer"),
+    )
+    .unwrap();
+    assert_eq!(load_owner(deps.as_ref().storage).unwrap(), "buyer");
+
+    // the operator was approved by the previous owner
+    assert!(
+        query_approvals(deps.as_ref(), at(0))
+            .unwrap()
+            .operators
+            .is_empty()
+    );
+    let err = try_transfer(
+        deps.as_mut(),
+        at(0),
+        mock_info("operator", &[]),
+        Addr::unchecked("operator"),
+    )
+    .unwrap_err();
+    assert_eq!(err, unauthorized());
+}
+
+#[test]
+fn operators_are_cleared_on_transfer() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+    try_approve_all(
+        deps.as_mut(),
+        at(0),
+        mock_info("owner", &[]),
+        Addr::unchecked("operator"),
+        None,
+    )
+    .unwrap();
+
+    // the ownable returns to its previous owner
+    for (from, to) in [("owner", "bob"), ("bob", "owner")] {
+        try_transfer(
+            deps.as_mut(),
+            at(0),
+            mock_info(from, &[]),
+            Addr::unchecked(to),
+        )
+        .unwrap();
+    }
+    assert_eq!(load_owner(deps.as_ref().storage).unwrap(), "owner");
+
+    assert!(
+        query_approvals(deps.as_ref(), at(0))
+            .unwrap()
+            .operators
+            .is_empty()
+    );
+    let err = try_transfer(
+        deps.as_mut(),
+        at(0),
+        mock_info("operator", &[]),
+        Addr::unchecked("operator"),
+    )
+    .unwrap_err();
+    assert_eq!(err, unauthorized());
+}
+
+#[test]
+fn revoked_operator_cannot_transfer() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+
+    try_approve_all(
+        deps.as_mut(),
+        at(0),
+        mock_info("owner", &[]),
+        Addr::unchecked("operator"),
+        Some(Timestamp::from_seconds(50)),
+    )
+    .unwrap();
+    try_revoke_all(
+        deps.as_mut(),
+        mock_info("owner", &[]),
+        Addr::unchecked("operator"),
+    )
+    .unwrap();
+
+    let err = try_transfer(
+        deps.as_mut(),
+        at(0),
+        mock_info("operator", &[]),
+        Addr::unchecked("buyer"),
+    )
+    .unwrap_err();
+    assert_eq!(err, unauthorized());
+}
+
+#[test]
+fn only_the_owner_manages_operators() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+
+    let err = try_approve_all(
+        deps.as_mut(),
+        at(0),
+        mock_info("stranger", &[]),
+        Addr::unchecked("stranger"),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, OwnableError::Unauthorized { .. }));
+    assert!(
+        query_approvals(deps.as_ref(), at(0))
+            .unwrap()
+            .operators
+            .is_empty()
+    );
+
+    try_approve_all(
+        deps.as_mut(),
+        at(0),
+        mock_info("owner", &[]),
+        Addr::unchecked("operator"),
+        None,
+    )
+    .unwrap();
+
+    // operators can't add other operators or revoke them
+    let err = try_approve_all(
+        deps.as_mut(),
+        at(0),
+        mock_info("operator", &[]),
+        Addr::unchecked("accomplice"),
+        None,
+    )
+    .unwrap_err();
+    assert!(matches!(err, OwnableError::Unauthorized { .. }));
+    for sender in ["operator", "stranger"] {
+        let err = try_revoke_all(
+            deps.as_mut(),
+            mock_info(sender, &[]),
+            Addr::unchecked("operator"),
+        )
+        .unwrap_err();
+        assert!(matches!(err, OwnableError::Unauthorized { .. }));
+    }
+
+    assert_eq!(
+        query_approvals(deps.as_ref(), at(0)).unwrap().operators,
+        vec![Approval {
+            spender: Addr::unchecked("operator"),
+            expires: None,
+        }]
+    );
+}
@@ -0,0 +1,235 @@
+use ownable_std::asset::{AssetError, AssetRef, AssetResolver, Cid};
+
+const CID_V0: &str = "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG";
+const CID_V1: &str = "bafybeie5nqv6kd3qnfjupgvz34woh3oksc3iau6abmyajn7qvtf6d2ho34";
+const CID_V1_BASE58: &str = "zdj7Wg2Qkk4mYgAkVU1kppfQ2sMGz5zPwERVpeWmxCQLDxVoC";
+const CID_RAW: &str = "bafkreifzjut3te2nhyekklss27nh3k72ysco7y32koao5eei66wof36n5e";
+const PACKAGE: &str = "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi";
+
+// sha2-256 digest shared by CID_V0, CID_V1 and CID_V1_BASE58
+const DIGEST: &str = "9d6c2be50f706953479ab9df2ce3edca90b68053c00b3004b7f0accbe1e8eedf";
+
+fn ipfs(cid: &str, path: Option<&str>) -> AssetRef {
+    AssetRef::Ipfs {
+        cid: Cid::parse(cid).unwrap(),
+        path: path.map(String::from),
+    }
+}
+
+#[test]
+fn parses_cids() {
+    let v0 = Cid::parse(CID_V0).unwrap();
+    assert_eq!(v0.version(), 0);
+    assert_eq!(v0.codec(), 0x70);
+    assert_eq!(v0.hash_code(), 0x12);
+    assert_eq!(hex::encode(v0.digest()), DIGEST);
+    assert_eq!(v0.as_str(), CID_V0);
+
+    for encoded in [CID_V1, CID_V1_BASE58] {
+        let v1 = Cid::parse(encoded).unwrap();
+        assert_eq!(v1.version(), 1);
+        assert_eq!(v1.codec(), 0x70);
+        assert_eq!(v1.hash_code(), 0x12);
+        assert_eq!(hex::encode(v1.digest()), DIGEST);
+        assert_eq!(v1.to_string(), encoded);
+    }
+
+    let raw = Cid::parse(CID_RAW).unwrap();
+    assert_eq!(raw.codec(), 0x55);
+    assert_eq!(
+        hex::encode(raw.digest()),
+        "b94d27b9934d3e08a52e52d7da7dabfac484efe37a5380ee9088f7ace2efcde9"
+    );
+
+    let hex = Cid::parse(&format!("f01701220{}", DIGEST)).unwrap();
+    assert_eq!(hex.digest(), v0.digest());
+    assert_eq!(
+        Cid::parse(&CID_V1.to_uppercase()).unwrap().digest(),
+        v0.digest()
+    );
+}
+
+#[test]
+fn rejects_malformed_cids() {
+    for cid in [
+        "",
+        "Qm",
+        // 0 is not in the base58 alphabet
+        "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbd0",
+        &CID_V0[..45],
+        &CID_V1[..CID_V1.len() - 4],
+        "bafybeie5nqv6kd3qnfjupgvz34woh3oksc3iau6abmyajn7qvtf6d2ho3!",
+        // unsupported multibase prefix
+        "mAXASIJ1sK+UPcGlTR5q53yzj7cqQtoBTwAswBLfwrMvh6O7f",
+        // version 2
+        &format!("f02701220{}", DIGEST),
+        // digest length doesn't match the digest
+        &format!("f01701221{}", DIGEST),
+        &format!("f01701220{}", &DIGEST[2..]),
+        "f017012",
+    ] {
+        assert_eq!(
+            Cid::parse(cid),
+            Err(AssetError::InvalidCid(cid.to_string())),
+            "{}",
+            cid
+        );
+    }
+}
+
+#[test]
+fn parses_asset_refs() {
+    assert_eq!(
+        AssetRef::parse(&format!("ipfs://{}", CID_V0)).unwrap(),
+        ipfs(CID_V0, None)
+    );
+    assert_eq!(
+        AssetRef::parse(&format!("ipfs://{}/images/./1.png", CID_V1)).unwrap(),
+        ipfs(CID_V1, Some("images/1.png"))
+    );
+    assert_eq!(
+        AssetRef::parse(&format!("ipfs://{}/", CID_V1)).unwrap(),
+        ipfs(CID_V1, None)
+    );
+    assert_eq!(AssetRef::parse(CID_RAW).unwrap(), ipfs(CID_RAW, None));
+    assert_eq!(
+        AssetRef::parse("images//1.png").unwrap(),
+        AssetRef::Package("images/1.png".into())
+    );
+    assert_eq!(
+        AssetRef::parse("data:image/svg+xml;base64,PHN2Zy8+").unwrap(),
+        AssetRef::Data("data:image/svg+xml;base64,PHN2Zy8+".into())
+    );
+    assert_eq!(
+        AssetRef::parse("https://example.com/1.png").unwrap(),
+        AssetRef::Https("https://example.com/1.png".into())
+    );
+}
+
+#[test]
+fn schemes_are_case_insensitive() {
+    assert_eq!(
+        AssetRef::parse(&format!("IPFS://{}/1.png", CID_V0)).unwrap(),
+        ipfs(CID_V0, Some("1.png"))
+    );
+    assert_eq!(
+        AssetRef::parse("HTTPS://example.com/1.png").unwrap(),
+        AssetRef::Https("HTTPS://example.com/1.png".into())
+    );
+    assert_eq!(
+        AssetRef::parse("Data:text/plain,hi").unwrap(),
+        AssetRef::Data("Data:text/plain,hi".into())
+    );
+
+    // displayed with the canonical scheme
+    assert_eq!(
+        AssetRef::parse(&format!("Ipfs://{}", CID_V0))
+            .unwrap()
+            .to_string(),
+        format!("ipfs://{}", CID_V0)
+    );
+}
+
+#[test]
+fn rejects_unsupported_schemes() {
+    for uri in [
+        "http://example.com/1.png",
+        "javascript:alert(1)",
+        "JavaScript:alert(1)",
+        "ftp://example.com/1.png",
+    ] {
+        assert_eq!(
+            AssetRef::parse(uri),
+            Err(AssetError::UnsupportedScheme(uri.to_string()))
+        );
+    }
+}
+
+#[test]
+fn rejects_paths_outside_the_package() {
+    for path in [
+        "../secret.png",
+        "images/../../secret.png",
+        "/etc/passwd",
+        "images\\1.png",
+    ] {
+        assert_eq!(
+            AssetRef::parse(path),
+            Err(AssetError::InvalidPath(path.to_string())),
+            "{}",
+            path
+        );
+    }
+
+    let uri = format!("ipfs://{}/images/../../1.png", CID_V0);
+    assert_eq!(
+        AssetRef::parse(&uri),
+        Err(AssetError::InvalidPath("images/../../1.png".into()))
+    );
+    assert!(matches!(
+        AssetRef::parse(&format!("ipfs://{}//etc/passwd", CID_V0)),
+        Err(AssetError::InvalidPath(_))
+    ));
+}
+
+#[test]
+fn resolves_asset_refs() {
+    let resolver = AssetResolver::default();
+    assert_eq!(
+        resolver.resolve(&ipfs(CID_V0, None)).unwrap(),
+        format!("https://ipfs.io/ipfs/{}", CID_V0)
+    );
+    assert_eq!(
+        resolver
+            .resolve(&ipfs(CID_V1, Some("images/1.png")))
+            .unwrap(),
+        format!("https://ipfs.io/ipfs/{}/images/1.png", CID_V1)
+    );
+    assert_eq!(
+        resolver
+            .resolve(&AssetRef::Https("https://example.com/1.png".into()))
+            .unwrap(),
+        "https://example.com/1.png"
+    );
+    assert_eq!(
+        resolver.resolve(&AssetRef::Package("images/1.png".into())),
+        Err(AssetError::MissingPackage("images/1.png".into()))
+    );
+
+    let resolver = AssetResolver::new("https://gateway.example/")
+        .with_package(PACKAGE)
+        .unwrap();
+    assert_eq!(
+        resolver
+            .resolve(&AssetRef::parse("images/1.png").unwrap())
+            .unwrap(),
+        format!("https://gateway.example/ipfs/{}/images/1.png", PACKAGE)
+    );
+    assert_eq!(
+        AssetResolver::default().with_package("images"),
+        Err(AssetError::InvalidCid("images".into()))
+    );
+}
+
+#[test]
+fn resolved_paths_are_percent_encoded() {
+    let resolver = AssetResolver::default().with_package(PACKAGE).unwrap();
+    let resolve = |s: &str| resolver.resolve(&AssetRef::parse(s).unwrap()).unwrap();
+
+    assert_eq!(
+        resolve("my images/1?.png#x"),
+        format!("https://ipfs.io/ipfs/{}/my%20images/1%3F.png%23x", PACKAGE)
+    );
+    assert_eq!(
+        resolve(&format!("ipfs://{}/a b/100%.png", CID_V0)),
+        format!("https://ipfs.io/ipfs/{}/a%20b/100%25.png", CID_V0)
+    );
+    // existing escapes and sub-delims are kept, other bytes are encoded
+    assert_eq!(
+        resolve("a%20b/(1)+x=y/étoile.png"),
+        format!(
+            "https://ipfs.io/ipfs/{}/a%20b/(1)+x=y/%C3%A9toile.png",
+            PACKAGE
+        )
+    );
+}
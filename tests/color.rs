@@ -0,0 +1,148 @@
+use ownable_std::color::ColorError;
+use ownable_std::{
+    Color, HashError, derive_rgb_values, get_random_color, try_derive_rgb_values,
+    try_get_random_color,
+};
+
+#[test]
+fn parses_hex_notations() {
+    assert_eq!(Color::parse("#F80").unwrap(), Color::rgb(255, 136, 0));
+    assert_eq!(
+        Color::parse("#F808").unwrap(),
+        Color::rgba(255, 136, 0, 136)
+    );
+    assert_eq!(Color::parse("#1E90FF").unwrap(), Color::rgb(30, 144, 255));
+    assert_eq!(
+        Color::parse("#1E90FF80").unwrap(),
+        Color::rgba(30, 144, 255, 128)
+    );
+    assert_eq!(Color::parse("#1e90ff").unwrap(), Color::rgb(30, 144, 255));
+    assert_eq!(Color::parse(" #1E90FF ").unwrap(), Color::rgb(30, 144, 255));
+}
+
+#[test]
+fn hash_sign_is_optional() {
+    assert_eq!(Color::parse("F80").unwrap(), Color::rgb(255, 136, 0));
+    assert_eq!(Color::parse("F808").unwrap(), Color::rgba(255, 136, 0, 136));
+    assert_eq!(Color::parse("3C9EE7").unwrap(), Color::rgb(60, 158, 231));
+    assert_eq!(
+        Color::parse("3C9EE7FF").unwrap(),
+        Color::rgba(60, 158, 231, 255)
+    );
+}
+
+#[test]
+fn rejects_invalid_colors() {
+    for s in [
+        "",
+        "#",
+        "##F80",
+        "#F8",
+        "#F8000",
+        "#1E90FF8",
+        "#1E90FF800",
+        "#GGGGGG",
+        "rgb(0, 0, 0)",
+        "#1E 0FF",
+        "#1É90F",
+    ] {
+        assert_eq!(
+            Color::parse(s),
+            Err(ColorError::InvalidFormat(s.to_string())),
+            "{}",
+            s
+        );
+    }
+}
+
+#[test]
+fn formats_as_hex() {
+    assert_eq!(Color::rgb(30, 144, 255).to_hex(), "#1E90FF");
+    assert_eq!(Color::rgba(30, 144, 255, 128).to_hex(), "#1E90FF80");
+    assert_eq!(Color::rgba(30, 144, 255, 128).to_string(), "#1E90FF80");
+    assert_eq!(
+        Color::rgba(30, 144, 255, 128).to_background_color(),
+        "1E90FF"
+    );
+    assert_eq!(Color::BLACK.to_hex(), "#000000");
+    assert_eq!(Color::WHITE.to_hex(), "#FFFFFF");
+    assert_eq!(
+        Color::from(Color::rgb(30, 144, 255).to_hsl()),
+        Color::rgb(30, 144, 255)
+    );
+}
+
+#[test]
+fn serde_round_trip() {
+    let color = Color::rgba(30, 144, 255, 128);
+    assert_eq!(serde_json::to_string(&color).unwrap(), r##""#1E90FF80""##);
+    assert_eq!(
+        serde_json::from_str::<Color>(r##""#1E90FF80""##).unwrap(),
+        color
+    );
+    assert_eq!(
+        cosmwasm_std::from_json::<Color>(&cosmwasm_std::to_json_binary(&color).unwrap()).unwrap(),
+        color
+    );
+
+    assert_eq!(
+        serde_json::from_str::<Color>(r#""f80""#).unwrap(),
+        Color::rgb(255, 136, 0)
+    );
+    assert!(serde_json::from_str::<Color>(r##""#12345""##).is_err());
+    assert!(serde_json::from_str::<Color>("[255, 136, 0]").is_err());
+}
+
+#[test]
+fn derives_colors_from_hashes() {
+    // the last three bytes of the hash, reversed
+    assert_eq!(
+        try_derive_rgb_values("0x112233aabbcc").unwrap(),
+        (204, 187, 170)
+    );
+    assert_eq!(try_get_random_color("112233aabbcc").unwrap(), "#CCBBAA");
+    assert_eq!(
+        Color::from_hash("112233aabbcc").unwrap(),
+        Color::rgb(204, 187, 170)
+    );
+    // an odd length is padded at the start
+    assert_eq!(try_derive_rgb_values("abbcc").unwrap(), (204, 187, 10));
+
+    // the lenient versions agree on valid hashes
+    assert_eq!(derive_rgb_values("112233aabbcc".into()), (204, 187, 170));
+    assert_eq!(get_random_color("112233aabbcc".into()), "#CCBBAA");
+}
+
+#[test]
+fn rejects_short_and_invalid_hashes() {
+    assert_eq!(
+        try_derive_rgb_values("aabb"),
+        Err(HashError::TooShort {
+            expected: 3,
+            actual: 2
+        })
+    );
+    assert_eq!(
+        try_get_random_color("0x"),
+        Err(HashError::TooShort {
+            expected: 3,
+            actual: 0
+        })
+    );
+    assert_eq!(
+        try_derive_rgb_values("xyz123"),
+        Err(HashError::InvalidHex("xyz123".into()))
+    );
+    assert_eq!(
+        try_get_random_color("aabbcc!"),
+        Err(HashError::InvalidHex("aabbcc!".into()))
+    );
+    assert_eq!(
+        Color::from_hash("not a hash"),
+        Err(HashError::InvalidHex("not a hash".into()))
+    );
+
+    // the lenient versions fall back to black or pad with zeros
+    assert_eq!(derive_rgb_values("xyz123".into()), (0, 0, 0));
+    assert_eq!(derive_rgb_values("aabb".into()), (187, 170, 0));
+}
@@ -0,0 +1,32 @@
+#![allow(dead_code)]
+
+use cosmwasm_std::{Addr, Attribute, Uint128};
+use ownable_std::{NFT, OwnableInfo};
+
+/// ownable owned by "owner", issued by "issuer"
+pub fn ownable_info() -> OwnableInfo {
+    OwnableInfo {
+        owner: Addr::unchecked("owner"),
+        issuer: Addr::unchecked("issuer"),
+        ownable_type: Some("ticket".into()),
+    }
+}
+
+/// nft the ownable is bridged to, released by the "lock_service" address
+pub fn nft() -> NFT {
+    NFT {
+        network: "eip155:1".into(),
+        id: Uint128::new(42),
+        address: "0xAbC0000000000000000000000000000000000001".into(),
+        lock_service: Some("lock_service".into()),
+    }
+}
+
+/// value of the attribute with the given key
+pub fn attribute<'a>(attributes: &'a [Attribute], key: &str) -> &'a str {
+    attributes
+        .iter()
+        .find(|attribute| attribute.key == key)
+        .map(|attribute| attribute.value.as_str())
+        .unwrap_or_else(|| panic!("no {} attribute", key))
+}
@@ -0,0 +1,198 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::{attribute, ownable_info};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{Addr, Response, Uint128};
+use ownable_std::consumable::{
+    BALANCE, init_balance, is_consumed, load_balance, query_balance, try_consume, try_consume_amount,
+};
+use ownable_std::consumer::{allow_consumable, verify_consume_event};
+use ownable_std::events::parse_events;
+use ownable_std::ownership::{init_ownable_id, init_ownership};
+use ownable_std::{
+    ExternalEventMsg, IdbStateDump, OwnableError, OwnableEvent, create_ownable_env, load_owned_deps,
+};
+
+// amount and remaining balance of the consume event in the response
+fn consumed(res: &Response) -> (Uint128, Uint128) {
+    match parse_events(res).unwrap().as_slice() {
+        [OwnableEvent::Consume { amount, remaining, .. }] => (*amount, *remaining),
+        events => panic!("expected a consume event, got {:?}", events),
+    }
+}
+
+fn consume_event(amount: &str, remaining: &str) -> ExternalEventMsg {
+    ExternalEventMsg {
+        network: None,
+        event_type: "ownable.consume".into(),
+        attributes: HashMap::from([
+            ("consumable".to_string(), "consumable".to_string()),
+            ("remaining".to_string(), remaining.to_string()),
+            ("owner".to_string(), "owner".to_string()),
+            ("issuer".to_string(), "issuer".to_string()),
+            ("ownable_type".to_string(), "ticket".to_string()),
+            ("amount".to_string(), amount.to_string()),
+        ]),
+    }
+}
+
+#[test]
+fn consume_in_units() {
+    let mut deps = load_owned_deps(None);
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+    init_balance(deps.as_mut().storage, Uint128::new(10)).unwrap();
+
+    let res = try_consume_amount(deps.as_mut(), mock_env(), mock_info("owner", &[]), Uint128::new(3)).unwrap();
+    assert_eq!(consumed(&res), (Uint128::new(3), Uint128::new(7)));
+    assert_eq!(attribute(&res.attributes, "remaining"), "7");
+
+    // the balance survives a round trip through the state dump
+    let dump = IdbStateDump::from(deps.storage);
+    let mut deps = load_owned_deps(Some(dump));
+    assert_eq!(query_balance(deps.as_ref()).unwrap().balance, Uint128::new(7));
+
+    let err = try_consume_amount(deps.as_mut(), mock_env(), mock_info("owner", &[]), Uint128::new(8)).unwrap_err();
+    assert!(matches!(err, OwnableError::ConsumeError { .. }));
+    let err = try_consume_amount(deps.as_mut(), mock_env(), mock_info("owner", &[]), Uint128::zero()).unwrap_err();
+    assert!(matches!(err, OwnableError::ConsumeError { .. }));
+
+    // consuming the rest marks the ownable as consumed
+    let res = try_consume(deps.as_mut(), mock_env(), mock_info("owner", &[])).unwrap();
+    assert_eq!(consumed(&res), (Uint128::new(7), Uint128::zero()));
+    assert_eq!(load_balance(deps.as_ref().storage).unwrap(), Uint128::zero());
+    let err = try_consume_amount(deps.as_mut(), mock_env(), mock_info("owner", &[]), Uint128::one()).unwrap_err();
+    assert_eq!(err, OwnableError::ConsumeError { val: "ownable is consumed".into() });
+}
+
+#[test]
+fn zero_balance_is_not_consumable() {
+    let mut deps = load_owned_deps(None);
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+    let err = init_balance(deps.as_mut().storage, Uint128::zero()).unwrap_err();
+    assert_eq!(err, OwnableError::ConsumeError { val: "balance must be greater than zero".into() });
+    assert_eq!(load_balance(deps.as_ref().storage).unwrap(), Uint128::one());
+
+    BALANCE.save(deps.as_mut().storage, &Uint128::zero()).unwrap();
+    let err = try_consume(deps.as_mut(), mock_env(), mock_info("owner", &[])).unwrap_err();
+    assert_eq!(err, OwnableError::ConsumeError { val: "nothing left to consume".into() });
+    assert!(!is_consumed(deps.as_ref().storage).unwrap());
+}
+
+#[test]
+fn consume_without_balance() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+    assert_eq!(query_balance(deps.as_ref()).unwrap().balance, Uint128::one());
+
+    let err = try_consume_amount(deps.as_mut(), mock_env(), mock_info("owner", &[]), Uint128::new(2)).unwrap_err();
+    assert!(matches!(err, OwnableError::ConsumeError { .. }));
+
+    let res = try_consume(deps.as_mut(), mock_env(), mock_info("owner", &[])).unwrap();
+    assert_eq!(consumed(&res), (Uint128::one(), Uint128::zero()));
+    assert_eq!(query_balance(deps.as_ref()).unwrap().balance, Uint128::zero());
+}
+
+#[test]
+fn consumer_validates_amount() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+    allow_consumable(deps.as_mut().storage, &Addr::unchecked("issuer"), "ticket").unwrap();
+    let info = mock_info("owner", &[]);
+
+    let event = verify_consume_event(deps.as_mut().storage, &info, &consume_event("2", "8")).unwrap();
+    assert_eq!(event.amount, Uint128::new(2));
+    event.assert_amount(Uint128::new(2)).unwrap();
+    assert!(event.assert_amount(Uint128::one()).is_err());
+
+    for amount in ["0", "-1", "two"] {
+        let err = verify_consume_event(deps.as_mut().storage, &info, &consume_event(amount, "5")).unwrap_err();
+        assert!(matches!(err, OwnableError::ConsumeError { .. }));
+    }
+
+    // events of ownables without a balance have no amount
+    let mut event = consume_event("1", "0");
+    event.attributes.remove("amount");
+    let event = verify_consume_event(deps.as_mut().storage, &info, &event).unwrap();
+    assert_eq!(event.amount, Uint128::one());
+}
+
+#[test]
+fn consumer_rejects_replayed_events() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+    allow_consumable(deps.as_mut().storage, &Addr::unchecked("issuer"), "ticket").unwrap();
+    let info = mock_info("owner", &[]);
+
+    verify_consume_event(deps.as_mut().storage, &info, &consume_event("2", "8")).unwrap();
+    let err = verify_consume_event(deps.as_mut().storage, &info, &consume_event("2", "8")).unwrap_err();
+    assert_eq!(err, OwnableError::ConsumeError { val: "consume event was already accepted".into() });
+
+    // the next consume of the same consumable leaves a lower balance
+    verify_consume_event(deps.as_mut().storage, &info, &consume_event("2", "6")).unwrap();
+
+    // other consumables are tracked separately
+    let mut event = consume_event("2", "8");
+    event.attributes.insert("consumable".into(), "other".into());
+    verify_consume_event(deps.as_mut().storage, &info, &event).unwrap();
+
+    // events without consumable id can't be told apart
+    let mut event = consume_event("1", "0");
+    event.attributes.insert("consumable".into(), String::new());
+    let err = verify_consume_event(deps.as_mut().storage, &info, &event).unwrap_err();
+    assert_eq!(err, OwnableError::ConsumeError { val: "consume event has no consumable id".into() });
+    let mut event = consume_event("1", "0");
+    event.attributes.remove("remaining");
+    let err = verify_consume_event(deps.as_mut().storage, &info, &event).unwrap_err();
+    assert_eq!(err, OwnableError::ConsumeError { val: "consume event is missing remaining".into() });
+}
+
+#[test]
+fn consume_event_is_accepted_once() {
+    let mut consumable = mock_dependencies();
+    init_ownership(consumable.as_mut().storage, &ownable_info(), None).unwrap();
+    let res = try_consume(consumable.as_mut(), mock_env(), mock_info("owner", &[])).unwrap();
+
+    let mut consumer = mock_dependencies();
+    init_ownership(consumer.as_mut().storage, &ownable_info(), None).unwrap();
+    allow_consumable(consumer.as_mut().storage, &Addr::unchecked("issuer"), "ticket").unwrap();
+
+    // relay the emitted event to the consumer
+    let event = ExternalEventMsg {
+        network: None,
+        event_type: res.events[0].ty.clone(),
+        attributes: res.events[0].attributes.iter().map(|a| (a.key.clone(), a.value.clone())).collect(),
+    };
+    let info = mock_info("owner", &[]);
+    let consumed = verify_consume_event(consumer.as_mut().storage, &info, &event).unwrap();
+    assert_eq!(consumed.consumable, mock_env().contract.address);
+    assert!(verify_consume_event(consumer.as_mut().storage, &info, &event).is_err());
+}
+
+#[test]
+fn consume_event_has_the_ownable_id() {
+    let mut consumable = load_owned_deps(None);
+    init_ownership(consumable.as_mut().storage, &ownable_info(), None).unwrap();
+    let env = create_ownable_env("lto:T", None);
+
+    // the host doesn't set the contract address
+    let err = try_consume(consumable.as_mut(), env.clone(), mock_info("owner", &[])).unwrap_err();
+    assert_eq!(err, OwnableError::ConsumeError { val: "ownable id is unknown".into() });
+
+    init_ownable_id(consumable.as_mut().storage, "potion-1").unwrap();
+    let res = try_consume(consumable.as_mut(), env, mock_info("owner", &[])).unwrap();
+    assert_eq!(attribute(&res.events[0].attributes, "consumable"), "potion-1");
+
+    let mut consumer = load_owned_deps(None);
+    init_ownership(consumer.as_mut().storage, &ownable_info(), None).unwrap();
+    allow_consumable(consumer.as_mut().storage, &Addr::unchecked("issuer"), "ticket").unwrap();
+
+    let event = ExternalEventMsg {
+        network: None,
+        event_type: res.events[0].ty.clone(),
+        attributes: res.events[0].attributes.iter().map(|a| (a.key.clone(), a.value.clone())).collect(),
+    };
+    let consumed = verify_consume_event(consumer.as_mut().storage, &mock_info("owner", &[]), &event).unwrap();
+    assert_eq!(consumed.consumable, Addr::unchecked("potion-1"));
+}
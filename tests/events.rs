@@ -0,0 +1,118 @@
+mod common;
+
+use common::{attribute, ownable_info};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{Addr, Event, Response, Uint128};
+use ownable_std::consumable::try_consume;
+use ownable_std::events::{OwnableEvent, parse_events};
+use ownable_std::lock::try_lock;
+use ownable_std::ownership::{init_ownership, try_transfer};
+use ownable_std::{NFT, OwnableInstantiate};
+
+struct InstantiateMsg;
+
+impl OwnableInstantiate for InstantiateMsg {
+    type Nft = NFT;
+
+    fn ownable_id(&self) -> &str {
+        "2bJ69cFXzS8AJTcCmzjc9oeHZmBrmMVUr8svJ1mTGpho"
+    }
+    fn package(&self) -> &str {
+        "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi"
+    }
+    fn nft(&self) -> Option<&NFT> {
+        None
+    }
+    fn ownable_type(&self) -> Option<&str> {
+        None
+    }
+    fn network_id(&self) -> u8 {
+        b'T'
+    }
+}
+
+#[test]
+fn actions_emit_standard_events() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+
+    let res = try_transfer(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        Addr::unchecked("buyer"),
+    )
+    .unwrap();
+    assert_eq!(
+        parse_events(&res).unwrap(),
+        vec![OwnableEvent::Transfer {
+            from: Addr::unchecked("owner"),
+            to: Addr::unchecked("buyer"),
+        }]
+    );
+
+    let res = try_consume(deps.as_mut(), mock_env(), mock_info("buyer", &[])).unwrap();
+    assert_eq!(res.events[0].ty, "ownable.consume");
+    assert_eq!(
+        parse_events(&res).unwrap(),
+        vec![OwnableEvent::Consume {
+            consumable: mock_env().contract.address,
+            owner: Addr::unchecked("buyer"),
+            issuer: Addr::unchecked("issuer"),
+            ownable_type: Some("ticket".into()),
+            amount: Uint128::one(),
+            remaining: Uint128::zero(),
+        }]
+    );
+
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+    let res = try_lock(deps.as_mut(), mock_info("owner", &[])).unwrap();
+    assert_eq!(
+        parse_events(&res).unwrap(),
+        vec![OwnableEvent::Lock {
+            owner: Addr::unchecked("owner"),
+            nonce: 1,
+        }]
+    );
+}
+
+#[test]
+fn instantiate_event() {
+    let mut ownable_info = ownable_info();
+    ownable_info.ownable_type = None;
+    let ownable_event = OwnableEvent::instantiate(&InstantiateMsg, &ownable_info);
+    let event = Event::from(ownable_event.clone());
+    assert_eq!(event.ty, "ownable.instantiate");
+    assert_eq!(attribute(&event.attributes, "ownable_type"), "");
+
+    // events reported by wasmd are prefixed
+    let mut prefixed = event.clone();
+    prefixed.ty = format!("wasm-{}", event.ty);
+    assert_eq!(
+        OwnableEvent::from_event(&prefixed).unwrap(),
+        Some(ownable_event)
+    );
+}
+
+#[test]
+fn parse_skips_other_events() {
+    let res: Response = Response::new()
+        .add_event(Event::new("color").add_attribute("color", "red"))
+        .add_event(
+            OwnableEvent::Unlock {
+                lock_service: Addr::unchecked("bridge"),
+            }
+            .into(),
+        );
+    assert_eq!(
+        parse_events(&res).unwrap(),
+        vec![OwnableEvent::Unlock {
+            lock_service: Addr::unchecked("bridge"),
+        }]
+    );
+
+    let res: Response =
+        Response::new().add_event(Event::new("ownable.transfer").add_attribute("from", "owner"));
+    assert!(parse_events(&res).is_err());
+}
@@ -0,0 +1,168 @@
+use cosmwasm_std::testing::mock_env;
+use cosmwasm_std::{Addr, Uint128};
+use ownable_std::instantiate::is_caip2_chain_id;
+use ownable_std::ownership::{NFT_ITEM, OWNABLE_INFO, init_ownable, load_ownable_id};
+use ownable_std::{NFT, OwnableError, OwnableInfo, OwnableInstantiate, load_owned_deps};
+
+struct InstantiateMsg {
+    ownable_id: String,
+    package: String,
+    nft: Option<NFT>,
+    ownable_type: Option<String>,
+}
+
+impl OwnableInstantiate for InstantiateMsg {
+    type Nft = NFT;
+
+    fn ownable_id(&self) -> &str {
+        &self.ownable_id
+    }
+    fn package(&self) -> &str {
+        &self.package
+    }
+    fn nft(&self) -> Option<&NFT> {
+        self.nft.as_ref()
+    }
+    fn ownable_type(&self) -> Option<&str> {
+        self.ownable_type.as_deref()
+    }
+    fn network_id(&self) -> u8 {
+        b'T'
+    }
+}
+
+fn msg() -> InstantiateMsg {
+    InstantiateMsg {
+        ownable_id: "2bJ69cFXzS8AJTcCmzjc9oeHZmBrmMVUr8svJ1mTGpho".into(),
+        package: "bafybeigdyrzt5sfp7udm7hu76uh7y26nf3efuylqabf3oclgtqy55fbzdi".into(),
+        nft: Some(NFT {
+            network: "eip155:1".into(),
+            id: Uint128::new(42),
+            address: "0x341".into(),
+            lock_service: None,
+        }),
+        ownable_type: Some("ticket".into()),
+    }
+}
+
+fn instantiate_error(val: &str) -> Result<(), OwnableError> {
+    Err(OwnableError::InstantiateError { val: val.into() })
+}
+
+#[test]
+fn valid_message() {
+    assert_eq!(msg().validate(), Ok(()));
+    assert_eq!(
+        InstantiateMsg {
+            nft: None,
+            ownable_type: None,
+            package: "QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG".into(),
+            ..msg()
+        }
+        .validate(),
+        Ok(())
+    );
+}
+
+#[test]
+fn rejects_empty_ownable_id() {
+    for ownable_id in ["", "  "] {
+        let msg = InstantiateMsg {
+            ownable_id: ownable_id.into(),
+            ..msg()
+        };
+        assert_eq!(msg.validate(), instantiate_error("ownable_id is empty"));
+    }
+}
+
+#[test]
+fn rejects_invalid_package_cid() {
+    for package in [
+        "",
+        "package",
+        "ipfs://QmYwAPJzv5CZsnA625s3Xf2nemtYgPpHdWEz79ojWnPbdG",
+    ] {
+        let msg = InstantiateMsg {
+            package: package.into(),
+            ..msg()
+        };
+        assert_eq!(
+            msg.validate(),
+            instantiate_error(&format!("package: invalid cid {}", package))
+        );
+    }
+}
+
+#[test]
+fn rejects_empty_ownable_type() {
+    let msg = InstantiateMsg {
+        ownable_type: Some(" ".into()),
+        ..msg()
+    };
+    assert_eq!(msg.validate(), instantiate_error("ownable_type is empty"));
+}
+
+#[test]
+fn rejects_invalid_nft() {
+    let mut msg = msg();
+    msg.nft.as_mut().unwrap().network = "ethereum".into();
+    assert_eq!(
+        msg.validate(),
+        instantiate_error("nft network ethereum is not a CAIP-2 chain id")
+    );
+
+    let mut msg = self::msg();
+    msg.nft.as_mut().unwrap().address = "".into();
+    assert_eq!(msg.validate(), instantiate_error("nft address is empty"));
+}
+
+#[test]
+fn stores_the_ownable() {
+    let msg = msg();
+    let ownable_info = OwnableInfo {
+        owner: Addr::unchecked("owner"),
+        issuer: Addr::unchecked("issuer"),
+        ownable_type: msg.ownable_type.clone(),
+    };
+    let mut deps = load_owned_deps(None);
+    init_ownable(deps.as_mut().storage, &msg, &ownable_info).unwrap();
+
+    let storage = deps.as_ref().storage;
+    assert_eq!(OWNABLE_INFO.load(storage).unwrap(), ownable_info);
+    assert_eq!(NFT_ITEM.may_load(storage).unwrap(), msg.nft);
+    assert_eq!(
+        load_ownable_id(storage, &mock_env()).unwrap(),
+        Addr::unchecked(msg.ownable_id)
+    );
+}
+
+#[test]
+fn caip2_chain_ids() {
+    for chain_id in [
+        "eip155:1",
+        "eip155:137",
+        "cosmos:cosmoshub-4",
+        "bip122:000000000019d6689c085ae165831e93",
+        "lto:T",
+        "polkadot:b0a8d493285c2df73290dfb7e61f870f",
+        "solana:5eykt4UsFv8P8NJdTREpY1vzqKqZKvdp",
+    ] {
+        assert!(is_caip2_chain_id(chain_id), "{}", chain_id);
+    }
+
+    for chain_id in [
+        "",
+        "eip155",
+        "eip155:",
+        ":1",
+        "ei:1",
+        "eip155abc:1",
+        "EIP155:1",
+        "eip_155:1",
+        "eip155:1:2",
+        "eip155:0x1!",
+        "cosmos:cosmoshub-4-and-a-reference-longer-than-32",
+    ] {
+        assert!(!is_caip2_chain_id(chain_id), "{}", chain_id);
+    }
+}
@@ -0,0 +1,95 @@
+use ownable_std::{LocalizedText, Metadata};
+
+fn text(name: Option<&str>, description: Option<&str>) -> LocalizedText {
+    LocalizedText {
+        name: name.map(String::from),
+        description: description.map(String::from),
+    }
+}
+
+fn metadata() -> Metadata {
+    Metadata {
+        name: Some("Dragon".into()),
+        description: Some("A dragon".into()),
+        ..Metadata::default()
+    }
+}
+
+fn name_and_description(metadata: &Metadata) -> (Option<&str>, Option<&str>) {
+    (metadata.name.as_deref(), metadata.description.as_deref())
+}
+
+#[test]
+fn falls_back_along_the_tag_chain() {
+    let metadata = metadata()
+        .with_localization("zh", text(Some("龙"), Some("一条龙")))
+        .with_localization("zh-Hant", text(Some("龍"), None));
+
+    // name from zh-Hant, description from zh
+    assert_eq!(
+        name_and_description(&metadata.localized("zh-Hant-TW")),
+        (Some("龍"), Some("一条龙"))
+    );
+    assert_eq!(
+        name_and_description(&metadata.localized("zh-Hans")),
+        (Some("龙"), Some("一条龙"))
+    );
+    assert_eq!(
+        name_and_description(&metadata.localized("zh")),
+        (Some("龙"), Some("一条龙"))
+    );
+
+    // no translation, the default fields are kept
+    assert_eq!(
+        name_and_description(&metadata.localized("de-DE")),
+        (Some("Dragon"), Some("A dragon"))
+    );
+    assert_eq!(
+        name_and_description(&metadata.localized("")),
+        (Some("Dragon"), Some("A dragon"))
+    );
+    assert_eq!(
+        name_and_description(&self::metadata().localized("zh")),
+        (Some("Dragon"), Some("A dragon"))
+    );
+}
+
+#[test]
+fn tags_are_normalized() {
+    let metadata = metadata()
+        .with_localization("pt_br", text(Some("Dragão"), None))
+        .with_localization("ZH-hant", text(Some("龍"), None));
+
+    for lang in ["pt-BR", "PT_BR", " pt-br ", "pt_BR_x_foo"] {
+        assert_eq!(
+            metadata.localized(lang).name.as_deref(),
+            Some("Dragão"),
+            "{}",
+            lang
+        );
+    }
+    assert_eq!(metadata.localized("zh_Hant_TW").name.as_deref(), Some("龍"));
+    assert_eq!(metadata.localized("pt").name.as_deref(), Some("Dragon"));
+}
+
+#[test]
+fn drops_dangling_singletons() {
+    let metadata = metadata()
+        .with_localization("pt", text(Some("Dragão"), None))
+        .with_localization("pt-BR-x", text(Some("Dragão x"), None))
+        .with_localization("pt-BR-x-foo", text(None, Some("Um dragão")));
+
+    // pt-BR-x-foo -> pt-BR -> pt, the private use singleton x is skipped
+    assert_eq!(
+        name_and_description(&metadata.localized("pt-BR-x-foo")),
+        (Some("Dragão"), Some("Um dragão"))
+    );
+    assert_eq!(
+        name_and_description(&metadata.localized("pt-BR-x-bar")),
+        (Some("Dragão"), Some("A dragon"))
+    );
+    assert_eq!(
+        name_and_description(&metadata.localized("pt-BR-u-ca-buddhist")),
+        (Some("Dragão"), Some("A dragon"))
+    );
+}
@@ -0,0 +1,224 @@
+mod common;
+
+use std::collections::HashMap;
+
+use common::{nft, ownable_info};
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{Addr, Uint128};
+use ownable_std::consumable::try_consume;
+use ownable_std::lock::{is_locked, try_lock, try_unlock_with_event};
+use ownable_std::ownership::{init_ownership, try_transfer};
+use ownable_std::{ExternalEventMsg, OwnableError};
+
+fn unlock_event() -> ExternalEventMsg {
+    ExternalEventMsg {
+        network: Some("eip155:1".into()),
+        event_type: "unlock".into(),
+        attributes: HashMap::from([
+            (
+                "contract".to_string(),
+                // the nft contract address is compared case insensitively
+                "0xabc0000000000000000000000000000000000001".to_string(),
+            ),
+            ("token_id".to_string(), "42".to_string()),
+            ("nonce".to_string(), "1".to_string()),
+        ]),
+    }
+}
+
+fn lock_error(val: &str) -> OwnableError {
+    OwnableError::LockError { val: val.into() }
+}
+
+#[test]
+fn locked_ownable_cannot_be_transferred_or_consumed() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), Some(&nft())).unwrap();
+
+    let err = try_lock(deps.as_mut(), mock_info("other", &[])).unwrap_err();
+    assert!(matches!(err, OwnableError::Unauthorized { .. }));
+
+    try_lock(deps.as_mut(), mock_info("owner", &[])).unwrap();
+    assert!(is_locked(deps.as_ref().storage).unwrap());
+    let err = try_lock(deps.as_mut(), mock_info("owner", &[])).unwrap_err();
+    assert_eq!(err, lock_error("ownable is locked"));
+
+    let err = try_transfer(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        Addr::unchecked("buyer"),
+    )
+    .unwrap_err();
+    assert_eq!(err, lock_error("ownable is locked"));
+    let err = try_consume(deps.as_mut(), mock_env(), mock_info("owner", &[])).unwrap_err();
+    assert_eq!(err, lock_error("ownable is locked"));
+}
+
+#[test]
+fn unlock_with_event() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), Some(&nft())).unwrap();
+    try_lock(deps.as_mut(), mock_info("owner", &[])).unwrap();
+
+    try_unlock_with_event(
+        deps.as_mut(),
+        mock_info("lock_service", &[]),
+        unlock_event(),
+    )
+    .unwrap();
+    assert!(!is_locked(deps.as_ref().storage).unwrap());
+
+    try_transfer(
+        deps.as_mut(),
+        mock_env(),
+        mock_info("owner", &[]),
+        Addr::unchecked("buyer"),
+    )
+    .unwrap();
+}
+
+#[test]
+fn unlock_rejects_invalid_events() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), Some(&nft())).unwrap();
+    try_lock(deps.as_mut(), mock_info("owner", &[])).unwrap();
+
+    let err =
+        try_unlock_with_event(deps.as_mut(), mock_info("owner", &[]), unlock_event()).unwrap_err();
+    assert_eq!(
+        err,
+        OwnableError::Unauthorized {
+            val: "event not sent by the lock service".into()
+        }
+    );
+
+    let mut event = unlock_event();
+    event.event_type = "lock".into();
+    let err =
+        try_unlock_with_event(deps.as_mut(), mock_info("lock_service", &[]), event).unwrap_err();
+    assert_eq!(err, lock_error("unexpected event type lock"));
+
+    let mut event = unlock_event();
+    event.network = Some("eip155:137".into());
+    let err =
+        try_unlock_with_event(deps.as_mut(), mock_info("lock_service", &[]), event).unwrap_err();
+    assert_eq!(err, lock_error("network mismatch"));
+
+    let mut event = unlock_event();
+    event.network = None;
+    let err =
+        try_unlock_with_event(deps.as_mut(), mock_info("lock_service", &[]), event).unwrap_err();
+    assert_eq!(err, lock_error("network mismatch"));
+
+    let mut event = unlock_event();
+    event.attributes.insert("contract".into(), "0xdef".into());
+    let err =
+        try_unlock_with_event(deps.as_mut(), mock_info("lock_service", &[]), event).unwrap_err();
+    assert_eq!(err, lock_error("nft contract mismatch"));
+
+    let mut event = unlock_event();
+    event.attributes.remove("contract");
+    let err =
+        try_unlock_with_event(deps.as_mut(), mock_info("lock_service", &[]), event).unwrap_err();
+    assert_eq!(err, lock_error("nft contract mismatch"));
+
+    let mut event = unlock_event();
+    event.attributes.insert("token_id".into(), "43".into());
+    let err =
+        try_unlock_with_event(deps.as_mut(), mock_info("lock_service", &[]), event).unwrap_err();
+    assert_eq!(err, lock_error("nft token id mismatch"));
+
+    let mut event = unlock_event();
+    event.attributes.remove("nonce");
+    let err =
+        try_unlock_with_event(deps.as_mut(), mock_info("lock_service", &[]), event).unwrap_err();
+    assert_eq!(err, lock_error("unlock event is not for the current lock"));
+
+    // none of the rejected events unlocked the ownable
+    assert!(is_locked(deps.as_ref().storage).unwrap());
+}
+
+#[test]
+fn unlock_event_cannot_be_replayed() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), Some(&nft())).unwrap();
+    try_lock(deps.as_mut(), mock_info("owner", &[])).unwrap();
+    try_unlock_with_event(
+        deps.as_mut(),
+        mock_info("lock_service", &[]),
+        unlock_event(),
+    )
+    .unwrap();
+
+    // the event of the first lock doesn't release the second one
+    try_lock(deps.as_mut(), mock_info("owner", &[])).unwrap();
+    let err = try_unlock_with_event(
+        deps.as_mut(),
+        mock_info("lock_service", &[]),
+        unlock_event(),
+    )
+    .unwrap_err();
+    assert_eq!(err, lock_error("unlock event is not for the current lock"));
+    assert!(is_locked(deps.as_ref().storage).unwrap());
+
+    let mut event = unlock_event();
+    event.attributes.insert("nonce".into(), "2".into());
+    try_unlock_with_event(deps.as_mut(), mock_info("lock_service", &[]), event).unwrap();
+    assert!(!is_locked(deps.as_ref().storage).unwrap());
+}
+
+#[test]
+fn unlock_requires_locked_bridged_ownable() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), Some(&nft())).unwrap();
+    let err = try_unlock_with_event(
+        deps.as_mut(),
+        mock_info("lock_service", &[]),
+        unlock_event(),
+    )
+    .unwrap_err();
+    assert_eq!(err, lock_error("ownable is not locked"));
+
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+    let err = try_unlock_with_event(
+        deps.as_mut(),
+        mock_info("lock_service", &[]),
+        unlock_event(),
+    )
+    .unwrap_err();
+    assert_eq!(err, lock_error("ownable is not bridged to an nft"));
+
+    let mut deps = mock_dependencies();
+    let nft = ownable_std::NFT {
+        lock_service: None,
+        ..nft()
+    };
+    init_ownership(deps.as_mut().storage, &ownable_info(), Some(&nft)).unwrap();
+    let err = try_unlock_with_event(
+        deps.as_mut(),
+        mock_info("lock_service", &[]),
+        unlock_event(),
+    )
+    .unwrap_err();
+    assert_eq!(err, lock_error("nft has no lock service"));
+}
+
+#[test]
+fn unlock_token_id_is_exact() {
+    let mut deps = mock_dependencies();
+    let nft = ownable_std::NFT {
+        id: Uint128::new(7),
+        ..nft()
+    };
+    init_ownership(deps.as_mut().storage, &ownable_info(), Some(&nft)).unwrap();
+    try_lock(deps.as_mut(), mock_info("owner", &[])).unwrap();
+
+    // "07" is the same number but a different token id string
+    let mut event = unlock_event();
+    event.attributes.insert("token_id".into(), "07".into());
+    let err =
+        try_unlock_with_event(deps.as_mut(), mock_info("lock_service", &[]), event).unwrap_err();
+    assert_eq!(err, lock_error("nft token id mismatch"));
+}
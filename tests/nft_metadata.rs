@@ -0,0 +1,239 @@
+use cosmwasm_std::{Timestamp, Uint128};
+use ownable_std::nft_metadata::{Cw721Metadata, Erc721Metadata, Erc1155Metadata, erc1155_uri};
+use ownable_std::{DisplayType, LocalizedText, Metadata, Trait, TraitValue};
+use serde_json::Value;
+
+const ERC721: &str = include_str!("fixtures/erc721.json");
+const ERC1155: &str = include_str!("fixtures/erc1155.json");
+const CW721: &str = include_str!("fixtures/cw721.json");
+
+fn json(s: &str) -> Value {
+    serde_json::from_str(s).unwrap()
+}
+
+#[test]
+fn erc721_fixture_round_trips() {
+    let erc721: Erc721Metadata = serde_json::from_str(ERC721).unwrap();
+    assert_eq!(json(&serde_json::to_string(&erc721).unwrap()), json(ERC721));
+    assert_eq!(erc721.extra["compiler"], "HashLips Art Engine");
+}
+
+#[test]
+fn erc721_import() {
+    let metadata = Metadata::from_erc721_json(ERC721).unwrap();
+    assert_eq!(metadata.name.as_deref(), Some("Dave Starbelly"));
+    assert_eq!(metadata.background_color.as_deref(), Some("3C9EE7"));
+
+    let attributes = metadata.attributes.unwrap();
+    assert_eq!(attributes.len(), 8);
+    assert_eq!(attributes[0], Trait::string("Base", "Starfish"));
+    assert_eq!(attributes[2].max_value, Some(10.into()));
+    assert_eq!(attributes[4], Trait::boost_number("Aqua Power", 40u32));
+    assert_eq!(
+        attributes[7],
+        Trait::date("birthday", Timestamp::from_seconds(1546360800))
+    );
+}
+
+#[test]
+fn erc721_export_is_lossless() {
+    let metadata = Metadata::from_erc721_json(ERC721).unwrap();
+    assert_eq!(metadata.extra["compiler"], "HashLips Art Engine");
+
+    let exported = metadata.to_erc721_json().unwrap();
+    assert_eq!(Metadata::from_erc721_json(&exported).unwrap(), metadata);
+    assert_eq!(json(&exported), json(ERC721));
+}
+
+#[test]
+fn erc1155_export_is_lossless() {
+    let metadata = Metadata::from_erc1155_json(ERC1155, Uint128::new(1)).unwrap();
+    assert_eq!(metadata.extra["decimals"], 0);
+    assert_eq!(
+        metadata.extra["properties"]["simple_property"],
+        "example value"
+    );
+
+    // everything but the substituted id survives
+    let mut expected: Erc1155Metadata = serde_json::from_str(ERC1155).unwrap();
+    expected.substitute_id(Uint128::new(1));
+    let exported = metadata.to_erc1155_json().unwrap();
+    assert_eq!(
+        json(&exported),
+        json(&serde_json::to_string(&expected).unwrap())
+    );
+}
+
+#[test]
+fn metadata_keeps_extra_fields() {
+    let metadata = Metadata::from_erc721_json(
+        r#"{"name": "Starfish", "compiler": "HashLips Art Engine", "edition": 3}"#,
+    )
+    .unwrap();
+    assert_eq!(metadata.extra["edition"], 3);
+
+    // the extra fields are part of the GetMetadata {} response
+    let binary = cosmwasm_std::to_json_binary(&metadata).unwrap();
+    assert_eq!(
+        json(std::str::from_utf8(binary.as_slice()).unwrap()),
+        json(
+            r#"{"name": "Starfish", "compiler": "HashLips Art Engine", "edition": 3, "image": null, "image_data": null, "external_url": null, "description": null, "background_color": null, "animation_url": null, "youtube_url": null}"#
+        )
+    );
+    assert_eq!(
+        cosmwasm_std::from_json::<Metadata>(&binary).unwrap(),
+        metadata
+    );
+}
+
+#[test]
+fn erc1155_fixture_round_trips() {
+    let erc1155: Erc1155Metadata = serde_json::from_str(ERC1155).unwrap();
+    assert_eq!(
+        json(&serde_json::to_string(&erc1155).unwrap()),
+        json(ERC1155)
+    );
+    assert_eq!(erc1155.decimals, Some(0));
+    assert!(erc1155.extra.contains_key("localization"));
+}
+
+#[test]
+fn erc1155_import_substitutes_id() {
+    let metadata = Metadata::from_erc1155_json(ERC1155, Uint128::new(314592)).unwrap();
+    assert_eq!(
+        metadata.image.as_deref(),
+        Some(
+            "https://s3.amazonaws.com/your-bucket/images/000000000000000000000000000000000000000000000000000000000004cce0.png"
+        )
+    );
+    assert_eq!(metadata.name.as_deref(), Some("Asset Name"));
+
+    let exported = metadata.to_erc1155_json().unwrap();
+    assert_eq!(
+        Metadata::from_erc1155_json(&exported, Uint128::new(1)).unwrap(),
+        metadata
+    );
+}
+
+#[test]
+fn erc1155_uri_is_zero_padded_lowercase_hex() {
+    assert_eq!(
+        erc1155_uri("ipfs://Qm/{id}.json", Uint128::new(0xABCDEF)),
+        "ipfs://Qm/0000000000000000000000000000000000000000000000000000000000abcdef.json"
+    );
+}
+
+#[test]
+fn cw721_fixture_round_trips() {
+    let cw721: Cw721Metadata = serde_json::from_str(CW721).unwrap();
+    let metadata = Metadata::try_from(cw721.clone()).unwrap();
+
+    let attributes = metadata.attributes.as_ref().unwrap();
+    assert_eq!(
+        attributes[0].value,
+        TraitValue::String("Constitution".into())
+    );
+    assert_eq!(attributes[1].display_type, Some(DisplayType::Number));
+    assert_eq!(attributes[1].value, TraitValue::Number(430.into()));
+
+    let exported = Cw721Metadata::from(metadata);
+    assert_eq!(exported, cw721);
+    assert_eq!(
+        json(&serde_json::to_string(&exported).unwrap()),
+        json(CW721)
+    );
+}
+
+#[test]
+fn erc721_to_cw721_and_back() {
+    let mut metadata = Metadata::from_erc721_json(ERC721).unwrap();
+    let cw721 = Cw721Metadata::from(metadata.clone());
+    assert_eq!(
+        json(&serde_json::to_string(&cw721.attributes.as_ref().unwrap()[4]).unwrap()),
+        json(r#"{"display_type": "boost_number", "trait_type": "Aqua Power", "value": "40"}"#)
+    );
+
+    // cw721 has no max_value, untyped numbers and extra fields, everything
+    // else survives
+    metadata.extra.clear();
+    let attributes = metadata.attributes.as_mut().unwrap();
+    attributes[2].max_value = None;
+    attributes[2].value = TraitValue::String("5".into());
+    attributes[3].value = TraitValue::String("1.4".into());
+    assert_eq!(Metadata::try_from(cw721).unwrap(), metadata);
+}
+
+#[test]
+fn cw721_rejects_non_numeric_values_for_numeric_traits() {
+    let mut cw721: Cw721Metadata = serde_json::from_str(CW721).unwrap();
+    cw721.attributes.as_mut().unwrap()[1].value = "many".into();
+    assert!(Metadata::try_from(cw721).is_err());
+}
+
+#[test]
+fn erc721_export_keeps_localizations() {
+    let metadata = Metadata::from_erc721_json(ERC721)
+        .unwrap()
+        .with_localization(
+            "de",
+            LocalizedText {
+                name: Some("Dave Sternbauch".into()),
+                description: None,
+            },
+        );
+    let exported = metadata.to_erc721_json().unwrap();
+    assert_eq!(Metadata::from_erc721_json(&exported).unwrap(), metadata);
+    assert_eq!(
+        Metadata::from_erc1155_json(&metadata.to_erc1155_json().unwrap(), Uint128::zero()).unwrap(),
+        metadata
+    );
+}
+
+#[test]
+fn value_only_and_boolean_traits() {
+    let metadata = Metadata::from_erc721_json(
+        r#"{
+            "name": "Starfish",
+            "attributes": [
+                {"value": 5},
+                {"value": "Rare"},
+                {"trait_type": "Shiny", "value": true}
+            ]
+        }"#,
+    )
+    .unwrap();
+    let attributes = metadata.attributes.as_ref().unwrap();
+    assert_eq!(attributes[0].trait_type, None);
+    assert_eq!(attributes[0].value, TraitValue::Number(5.into()));
+    assert_eq!(attributes[1].value, TraitValue::String("Rare".into()));
+    assert_eq!(attributes[2], Trait::boolean("Shiny", true));
+
+    let exported = metadata.to_erc721_json().unwrap();
+    assert_eq!(
+        json(&exported)["attributes"],
+        json(r#"[{"value": 5}, {"value": "Rare"}, {"trait_type": "Shiny", "value": true}]"#)
+    );
+
+    // cw721 needs a trait_type and string values
+    let cw721 = Cw721Metadata::from(metadata);
+    let attributes = cw721.attributes.as_ref().unwrap();
+    assert_eq!(attributes[0].trait_type, "");
+    assert_eq!(attributes[2].value, "true");
+    let attributes = Metadata::try_from(cw721).unwrap().attributes.unwrap();
+    assert_eq!(attributes[0].trait_type, None);
+    assert_eq!(attributes[2], Trait::string("Shiny", "true"));
+}
+
+#[test]
+fn metadata_without_attributes_omits_them() {
+    let metadata = Metadata {
+        name: Some("Starfish".into()),
+        ..Metadata::default()
+    };
+    let serialized = serde_json::to_string(&metadata).unwrap();
+    assert!(!serialized.contains("attributes"));
+    assert_eq!(
+        serde_json::from_str::<Metadata>(&serialized).unwrap(),
+        metadata
+    );
+}
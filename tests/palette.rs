@@ -0,0 +1,90 @@
+use ownable_std::HashError;
+use ownable_std::palette::{ContrastError, Harmony, Hsl, Palette, contrast_ratio};
+
+const HASH: &str = "3c9ee7a5f01d42b86e19c0d7a2b45f8e";
+
+fn assert_distinct(palette: &Palette) {
+    let hex = palette.to_hex();
+    for (i, color) in hex.iter().enumerate() {
+        assert!(!hex[..i].contains(color), "{} repeats in {:?}", color, hex);
+    }
+}
+
+#[test]
+fn derive_is_deterministic() {
+    let palette = Palette::derive(HASH, Harmony::Triadic, 3).unwrap();
+    assert_eq!(palette, Palette::derive(HASH, Harmony::Triadic, 3).unwrap());
+    assert_eq!(palette.colors.len(), 3);
+    assert_distinct(&palette);
+
+    assert_eq!(
+        Palette::derive("3c9ee7", Harmony::Triadic, 3),
+        Err(HashError::TooShort {
+            expected: 4,
+            actual: 3
+        })
+    );
+}
+
+#[test]
+fn hsl_round_trips_rgb() {
+    let hsl = Hsl::from_rgb(30, 144, 255);
+    assert_eq!(hsl.to_rgb(), (30, 144, 255));
+    assert_eq!(hsl.to_hex(), "#1E90FF");
+    assert_eq!(hsl.to_rgb_css(), "rgb(30, 144, 255)");
+    assert_eq!(hsl.to_hsl_css(), "hsl(210, 100%, 56%)");
+}
+
+#[test]
+fn contrast_ratio_is_reached() {
+    for background in [(255, 255, 255), (0, 0, 0), (40, 44, 52)] {
+        for harmony in [Harmony::Complementary, Harmony::Analogous, Harmony::Triadic] {
+            let palette = Palette::derive(HASH, harmony, 9)
+                .unwrap()
+                .with_contrast(background, 4.5)
+                .unwrap();
+            for color in &palette.colors {
+                assert!(contrast_ratio(color.to_rgb(), background) >= 4.5);
+            }
+            assert_distinct(&palette);
+        }
+    }
+}
+
+#[test]
+fn contrast_keeps_hue_and_saturation() {
+    let palette = Palette::derive(HASH, Harmony::Complementary, 2).unwrap();
+    let adjusted = palette.clone().with_contrast((255, 255, 255), 7.0).unwrap();
+    for (color, original) in adjusted.colors.iter().zip(&palette.colors) {
+        assert_eq!(color.hue, original.hue);
+        assert_eq!(color.saturation, original.saturation);
+    }
+}
+
+#[test]
+fn unreachable_contrast_is_reported() {
+    // no color has a contrast ratio of 7 against mid gray
+    let background = (128, 128, 128);
+    let err = Palette::derive(HASH, Harmony::Triadic, 9)
+        .unwrap()
+        .with_contrast(background, 7.0)
+        .unwrap_err();
+    assert_eq!(err.below_ratio, (0..9).collect::<Vec<_>>());
+    assert!(err.not_distinct.is_empty());
+    for color in &err.palette.colors {
+        assert!(contrast_ratio(color.to_rgb(), background) < 7.0);
+    }
+    assert_distinct(&err.palette);
+    assert!(matches!(err, ContrastError { min_ratio: 7.0, .. }));
+}
+
+#[test]
+fn colors_that_cant_be_kept_distinct_are_reported() {
+    // only black has a contrast ratio of 21 against white
+    let palette = Palette {
+        colors: vec![Hsl::new(0.0, 0.0, 0.0), Hsl::new(0.0, 0.0, 0.0)],
+    };
+    let err = palette.with_contrast((255, 255, 255), 21.0).unwrap_err();
+    assert!(err.below_ratio.is_empty());
+    assert_eq!(err.not_distinct, vec![1]);
+}
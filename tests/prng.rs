@@ -0,0 +1,150 @@
+use ownable_std::HashError;
+use ownable_std::prng::{OwnableRng, RngCore};
+
+const HASH: &str = "3c9ee7a5f01d42b86e19c0d7a2b45f8e";
+
+fn seed(hex: &str) -> [u8; 32] {
+    hex::decode(hex).unwrap().try_into().unwrap()
+}
+
+fn rng() -> OwnableRng {
+    OwnableRng::from_hash(HASH).unwrap()
+}
+
+#[test]
+fn chacha20_stream() {
+    // first block of the RFC 7539 ChaCha20 test vector with an all zero key
+    let mut rng = OwnableRng::from_seed([0; 32]);
+    assert_eq!(rng.next_u64(), 0x903df1a0ade0b876);
+    assert_eq!(rng.next_u64(), 0x28bd8653e56a5d40);
+}
+
+#[test]
+fn from_hash() {
+    // seeded with sha256(3c9ee7...)
+    let mut expected = OwnableRng::from_seed(seed(
+        "6c978e146c0339151fcabd0db8ac705171e5a2fb2a1969dd7063eb5ce9b668f7",
+    ));
+    assert_eq!(expected.next_u64(), 0xcaf7477c9bb63070);
+    assert_eq!(rng().next_u64(), 0xcaf7477c9bb63070);
+
+    // 0x prefix and case don't matter
+    let mut rng = OwnableRng::from_hash(&format!("0x{}", HASH.to_uppercase())).unwrap();
+    assert_eq!(rng.next_u64(), 0xcaf7477c9bb63070);
+
+    assert_eq!(
+        OwnableRng::from_hash("3c9ee7a5").unwrap_err(),
+        HashError::TooShort {
+            expected: 16,
+            actual: 4
+        }
+    );
+    assert_eq!(
+        OwnableRng::from_hash("not a hash").unwrap_err(),
+        HashError::InvalidHex("not a hash".into())
+    );
+}
+
+#[test]
+fn from_ownable() {
+    // seeded with sha256("ownable-1" || 0x00 || 3c9ee7...)
+    let mut expected = OwnableRng::from_seed(seed(
+        "23bd3d37434453a07a6b96b5b1af10d54a937f8a6527f3cb3141f9e0da006ea7",
+    ));
+    assert_eq!(expected.next_u64(), 0x1cb209f269919ac5);
+
+    let mut rng = OwnableRng::from_ownable("ownable-1", HASH).unwrap();
+    assert_eq!(rng.next_u64(), 0x1cb209f269919ac5);
+
+    let mut other = OwnableRng::from_ownable("ownable-2", HASH).unwrap();
+    assert_ne!(other.next_u64(), 0x1cb209f269919ac5);
+    assert!(OwnableRng::from_ownable("ownable-1", "3c9ee7a5").is_err());
+}
+
+#[test]
+fn gen_range() {
+    let mut rng = rng();
+    let values: Vec<u64> = (0..8).map(|_| rng.gen_range(10, 20)).collect();
+    assert_eq!(values, [16, 10, 15, 13, 19, 19, 19, 19]);
+
+    assert_eq!(rng.gen_range(7, 8), 7);
+    assert!(rng.gen_range(0, u64::MAX) < u64::MAX);
+}
+
+#[test]
+#[should_panic(expected = "empty range 5..5")]
+fn gen_range_panics_on_an_empty_range() {
+    rng().gen_range(5, 5);
+}
+
+#[test]
+fn gen_f64_and_gen_ratio() {
+    let mut rng = rng();
+    assert_eq!(rng.gen_f64(), 0.7928356818408353);
+    let values: Vec<bool> = (0..8).map(|_| rng.gen_ratio(1, 3)).collect();
+    assert_eq!(
+        values,
+        [false, false, false, true, true, false, false, false]
+    );
+}
+
+#[test]
+#[should_panic(expected = "empty range 0..0")]
+fn gen_ratio_panics_on_a_zero_denominator() {
+    rng().gen_ratio(1, 0);
+}
+
+#[test]
+fn choose() {
+    let mut rng = rng();
+    let values: Vec<&str> = (0..6)
+        .map(|_| *rng.choose(&["a", "b", "c"]).unwrap())
+        .collect();
+    assert_eq!(values, ["b", "c", "b", "b", "a", "a"]);
+    assert_eq!(rng.choose::<u8>(&[]), None);
+}
+
+#[test]
+fn choose_weighted() {
+    let items = [("common", 70), ("rare", 25), ("legendary", 5), ("never", 0)];
+    let mut rng = rng();
+    let values: Vec<&str> = (0..10)
+        .map(|_| *rng.choose_weighted(&items).unwrap())
+        .collect();
+    assert_eq!(
+        values,
+        [
+            "common", "rare", "rare", "common", "common", "common", "common", "rare", "common",
+            "rare"
+        ]
+    );
+
+    // zero weight items are never picked
+    for _ in 0..100 {
+        assert_ne!(rng.choose_weighted(&items), Some(&"never"));
+    }
+    assert_eq!(
+        rng.choose_weighted(&[("only", 0), ("one", 1)]),
+        Some(&"one")
+    );
+}
+
+#[test]
+fn choose_weighted_without_weight() {
+    let mut rng = rng();
+    assert_eq!(rng.choose_weighted::<&str>(&[]), None);
+    assert_eq!(rng.choose_weighted(&[("a", 0), ("b", 0)]), None);
+}
+
+#[test]
+fn shuffle() {
+    let mut items: Vec<u32> = (0..10).collect();
+    rng().shuffle(&mut items);
+    assert_eq!(items, [1, 0, 2, 8, 9, 3, 4, 7, 5, 6]);
+
+    let mut empty: [u32; 0] = [];
+    rng().shuffle(&mut empty);
+    let mut single = [1];
+    rng().shuffle(&mut single);
+    assert_eq!(single, [1]);
+}
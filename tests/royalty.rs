@@ -0,0 +1,66 @@
+use cosmwasm_std::testing::mock_dependencies;
+use cosmwasm_std::{Addr, Uint128};
+use ownable_std::OwnableError;
+use ownable_std::royalty::{Eip2981RoyaltyInfo, Royalty, init_royalty, query_royalty_info};
+
+#[test]
+fn royalty_info() {
+    let mut deps = mock_dependencies();
+    let res = query_royalty_info(deps.as_ref(), Uint128::new(1_000)).unwrap();
+    assert_eq!(res.address, "");
+    assert_eq!(res.royalty_amount, Uint128::zero());
+
+    let royalty = Royalty::new(Addr::unchecked("creator"), 250).unwrap();
+    init_royalty(deps.as_mut().storage, &royalty).unwrap();
+
+    let res = query_royalty_info(deps.as_ref(), Uint128::new(1_000)).unwrap();
+    assert_eq!(res.address, "creator");
+    assert_eq!(res.royalty_amount, Uint128::new(25));
+
+    // rounded down
+    let res = query_royalty_info(deps.as_ref(), Uint128::new(39)).unwrap();
+    assert_eq!(res.royalty_amount, Uint128::zero());
+
+    // no overflow for large prices
+    let res = query_royalty_info(deps.as_ref(), Uint128::MAX).unwrap();
+    assert_eq!(
+        res.royalty_amount,
+        Uint128::MAX.multiply_ratio(1u128, 40u128)
+    );
+}
+
+#[test]
+fn invalid_royalty() {
+    let err = Royalty::new(Addr::unchecked("creator"), 10_001).unwrap_err();
+    assert!(matches!(err, OwnableError::RoyaltyError { .. }));
+
+    let mut deps = mock_dependencies();
+    let royalty = Royalty {
+        recipient: Addr::unchecked("creator"),
+        basis_points: 20_000,
+    };
+    assert!(init_royalty(deps.as_mut().storage, &royalty).is_err());
+}
+
+#[test]
+fn eip2981() {
+    let royalty = Royalty::new(Addr::unchecked("0x8f1a"), 500).unwrap();
+    let info = royalty.to_eip2981(Uint128::new(2_000));
+    assert_eq!(info.receiver, "0x8f1a");
+    assert_eq!(info.royalty_amount, Uint128::new(100));
+    assert_eq!(
+        serde_json::to_string(&info).unwrap(),
+        r#"{"receiver":"0x8f1a","royaltyAmount":"100"}"#
+    );
+
+    let sale_price = Uint128::new(1_000_000_000_000_000_000);
+    let info = royalty.to_eip2981(sale_price);
+    assert_eq!(Royalty::from_eip2981(&info, sale_price).unwrap(), royalty);
+
+    let info = Eip2981RoyaltyInfo {
+        receiver: "0x8f1a".into(),
+        royalty_amount: Uint128::new(3),
+    };
+    assert!(Royalty::from_eip2981(&info, Uint128::new(2)).is_err());
+    assert!(Royalty::from_eip2981(&info, Uint128::zero()).is_err());
+}
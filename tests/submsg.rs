@@ -0,0 +1,207 @@
+mod common;
+
+use common::attribute;
+use cosmwasm_std::testing::mock_info;
+use cosmwasm_std::{
+    Addr, DepsMut, Env, MessageInfo, Reply, ReplyOn, Response, SubMsg, SubMsgResult, Uint128,
+};
+use cw_storage_plus::Item;
+use ownable_std::consumable::{is_consumed, try_consume};
+use ownable_std::ownership::{assert_owner, init_ownership};
+use ownable_std::submsg::{OwnableSubMsg, SubMsgSender, execute_submsg};
+use ownable_std::{
+    IdbStateDump, OwnableError, OwnableEvent, OwnableInfo, create_env, load_owned_deps,
+};
+use serde::{Deserialize, Serialize};
+
+// units the consumer ownable has consumed
+const CONSUMED: Item<Uint128> = Item::new("consumed");
+
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+enum ExecuteMsg {
+    Consume {},
+}
+
+// execute entrypoint of the consumable contract
+fn execute(
+    deps: DepsMut,
+    env: Env,
+    info: MessageInfo,
+    msg: ExecuteMsg,
+) -> Result<Response, OwnableError> {
+    match msg {
+        ExecuteMsg::Consume {} => try_consume(deps, env, info),
+    }
+}
+
+// reply entrypoint of the consumer contract. the owner of the consumed
+// ownable must own the consumer as well
+fn reply(deps: DepsMut, _env: Env, reply: Reply) -> Result<Response, OwnableError> {
+    let events = match reply.result {
+        SubMsgResult::Ok(response) => response.events,
+        SubMsgResult::Err(error) => {
+            return Ok(Response::new().add_attribute("consume_error", error));
+        }
+    };
+    for event in &events {
+        if let Some(OwnableEvent::Consume { owner, amount, .. }) = OwnableEvent::from_event(event)?
+        {
+            assert_owner(deps.storage, &owner)?;
+            let consumed = CONSUMED.may_load(deps.storage)?.unwrap_or_default();
+            CONSUMED.save(deps.storage, &(consumed + amount))?;
+        }
+    }
+    Ok(Response::new().add_attribute("action", "reply"))
+}
+
+fn state(owner: &str, ownable_type: &str) -> IdbStateDump {
+    let mut deps = load_owned_deps(None);
+    let ownable_info = OwnableInfo {
+        owner: Addr::unchecked(owner),
+        issuer: Addr::unchecked("issuer"),
+        ownable_type: Some(ownable_type.into()),
+    };
+    init_ownership(deps.as_mut().storage, &ownable_info, None).unwrap();
+    IdbStateDump::from(deps.storage)
+}
+
+type ReplyFn = fn(DepsMut, Env, Reply) -> Result<Response, OwnableError>;
+
+// the consumer ownable, executed by `user`
+fn consumer(owner: &str, user: &str) -> SubMsgSender<ReplyFn> {
+    SubMsgSender {
+        ownable_id: "consumer".into(),
+        state: state(owner, "dragon"),
+        info: mock_info(user, &[]),
+        reply,
+    }
+}
+
+fn consume(reply_on: ReplyOn) -> OwnableSubMsg {
+    OwnableSubMsg::new(7, "consumable", &ExecuteMsg::Consume {}, reply_on).unwrap()
+}
+
+#[test]
+fn submsg_roundtrip() {
+    let msg = consume(ReplyOn::Always);
+    let response = Response::<cosmwasm_std::Empty>::new().add_submessage(SubMsg::from(msg.clone()));
+    assert_eq!(OwnableSubMsg::from_response(&response).unwrap(), vec![msg]);
+}
+
+#[test]
+fn user_owning_both_ownables_consumes() {
+    let execution = execute_submsg(
+        &consume(ReplyOn::Success),
+        consumer("user", "user"),
+        &state("user", "potion"),
+        create_env(),
+        execute,
+    )
+    .unwrap();
+
+    // the consumable is consumed by the user, not by the consumer ownable
+    let deps = load_owned_deps(Some(execution.state));
+    assert!(is_consumed(deps.as_ref().storage).unwrap());
+
+    let reply = execution.reply.unwrap();
+    assert_eq!(reply.id, 7);
+    let events = reply.result.unwrap().events;
+    assert_eq!(events[0].ty, "wasm");
+    assert_eq!(events[1].ty, "wasm-ownable.consume");
+    for event in &events {
+        assert_eq!(
+            attribute(&event.attributes, "_contract_address"),
+            "consumable"
+        );
+    }
+
+    // the reply ran on the consumer
+    assert_eq!(
+        execution.reply_response.unwrap(),
+        Response::new().add_attribute("action", "reply")
+    );
+    let deps = load_owned_deps(Some(execution.sender_state));
+    assert_eq!(
+        CONSUMED.load(deps.as_ref().storage).unwrap(),
+        Uint128::one()
+    );
+}
+
+#[test]
+fn without_reply_the_sender_is_unchanged() {
+    let consumer = consumer("user", "user");
+    let sender_state = consumer.state.clone();
+    let execution = execute_submsg(
+        &consume(ReplyOn::Never),
+        consumer,
+        &state("user", "potion"),
+        create_env(),
+        execute,
+    )
+    .unwrap();
+    assert!(execution.response.is_some());
+    assert!(execution.reply.is_none());
+    assert!(execution.reply_response.is_none());
+    assert_eq!(execution.sender_state, sender_state);
+}
+
+#[test]
+fn failed_reply_rolls_back_both_ownables() {
+    // the user owns the consumable, but not the consumer
+    let err = execute_submsg(
+        &consume(ReplyOn::Success),
+        consumer("other", "user"),
+        &state("user", "potion"),
+        create_env(),
+        execute,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        OwnableError::SubMsgError {
+            val: "reply of consumer failed: Unauthorized: not the owner".into()
+        }
+    );
+}
+
+#[test]
+fn failed_execution() {
+    let state = state("user", "potion");
+
+    // the sender handles the error in its reply, the target state is unchanged
+    let execution = execute_submsg(
+        &consume(ReplyOn::Error),
+        consumer("stranger", "stranger"),
+        &state,
+        create_env(),
+        execute,
+    )
+    .unwrap();
+    assert_eq!(execution.state, state);
+    assert!(execution.response.is_none());
+    assert_eq!(
+        execution.reply.unwrap().result.unwrap_err(),
+        "Unauthorized: not the owner"
+    );
+    assert_eq!(
+        execution.reply_response.unwrap(),
+        Response::new().add_attribute("consume_error", "Unauthorized: not the owner")
+    );
+
+    // otherwise the whole flow fails
+    let err = execute_submsg(
+        &consume(ReplyOn::Never),
+        consumer("stranger", "stranger"),
+        &state,
+        create_env(),
+        execute,
+    )
+    .unwrap_err();
+    assert_eq!(
+        err,
+        OwnableError::SubMsgError {
+            val: "consumable failed: Unauthorized: not the owner".into()
+        }
+    );
+}
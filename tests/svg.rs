@@ -0,0 +1,119 @@
+use ownable_std::Color;
+use ownable_std::svg::{
+    Element, Svg, circle, ellipse, layer, line, linear_gradient, path, polygon, radial_gradient,
+    rect, text,
+};
+
+#[test]
+fn renders_documents() {
+    let sky = Color::rgb(30, 144, 255);
+    let svg = Svg::new(100.0, 50.0)
+        .view_box(0.0, 0.0, 100.0, 50.0)
+        .def(linear_gradient("sky", &[(0.0, &sky), (1.5, &"#FFF")]))
+        .layer(rect(0.0, 0.0, 100.0, 50.0).fill("url(#sky)"))
+        .layer(
+            layer("shapes")
+                .child(circle(50.0, 25.0, 12.5).fill("#F80").stroke("black", 0.5))
+                .child(ellipse(10.0, 10.0, 4.0, 2.0).opacity(0.25))
+                .child(line(0.0, 0.0, -0.0, 50.0).transform("rotate(45)"))
+                .child(polygon(&[(0.0, 0.0), (10.0, 0.0), (5.0, 8.66)]))
+                .child(path("M0 0 L10 10")),
+        );
+
+    assert_eq!(
+        svg.render(),
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="100" height="50" viewBox="0 0 100 50">"#,
+            r##"<defs><linearGradient id="sky"><stop offset="0%" stop-color="#1E90FF"/><stop offset="100%" stop-color="#FFF"/></linearGradient></defs>"##,
+            r#"<rect x="0" y="0" width="100" height="50" fill="url(#sky)"/>"#,
+            r#"<g id="shapes">"#,
+            r##"<circle cx="50" cy="25" r="12.5" fill="#F80" stroke="black" stroke-width="0.5"/>"##,
+            r#"<ellipse cx="10" cy="10" rx="4" ry="2" opacity="0.25"/>"#,
+            r#"<line x1="0" y1="0" x2="0" y2="50" transform="rotate(45)"/>"#,
+            r#"<polygon points="0,0 10,0 5,8.66"/>"#,
+            r#"<path d="M0 0 L10 10"/>"#,
+            r#"</g></svg>"#,
+        )
+    );
+    assert_eq!(svg.to_string(), svg.render());
+}
+
+#[test]
+fn renders_data_uris() {
+    let svg = Svg::new(1.0, 1.0).layer(rect(0.0, 0.0, 1.0, 1.0).fill("red"));
+    assert_eq!(
+        svg.render(),
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><rect x="0" y="0" width="1" height="1" fill="red"/></svg>"#
+    );
+    assert_eq!(
+        svg.to_data_uri(),
+        "data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciIHdpZHRoPSIxIiBoZWlnaHQ9IjEiPjxyZWN0IHg9IjAiIHk9IjAiIHdpZHRoPSIxIiBoZWlnaHQ9IjEiIGZpbGw9InJlZCIvPjwvc3ZnPg=="
+    );
+}
+
+#[test]
+fn non_finite_numbers_render_as_zero() {
+    let svg = Svg::new(f64::NAN, f64::INFINITY).def(radial_gradient("glow", &[(-1.0, &"white")]));
+    assert_eq!(
+        svg.render(),
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="0" height="0"><defs><radialGradient id="glow"><stop offset="0%" stop-color="white"/></radialGradient></defs></svg>"#
+    );
+}
+
+#[test]
+fn escapes_text() {
+    let svg = Svg::new(10.0, 10.0).layer(text(1.0, 2.0, r#"<script>"Tom" & 'Jerry'</script>"#));
+    assert_eq!(
+        svg.render(),
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"><text x="1" y="2">&lt;script&gt;"Tom" &amp; 'Jerry'&lt;/script&gt;</text></svg>"#
+    );
+}
+
+#[test]
+fn escapes_attribute_values() {
+    let element = Element::new("g")
+        .id(r#"a" onload="alert(1)"#)
+        .attr("class", "<b> & 'c'");
+    let svg = Svg::new(10.0, 10.0).layer(element);
+    assert_eq!(
+        svg.render(),
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10"><g id="a&quot; onload=&quot;alert(1)" class="&lt;b&gt; &amp; &#39;c&#39;"/></svg>"#
+    );
+}
+
+#[test]
+fn drops_unsafe_elements_and_attributes() {
+    let svg = Svg::new(10.0, 10.0)
+        .layer(
+            Element::new("a")
+                .attr("href", "javascript:alert(1)")
+                .attr("xlink:href", " JavaScript&#58;alert(1)")
+                .attr("onclick", "alert(1)")
+                .child(text(0.0, 0.0, "link")),
+        )
+        .layer(Element::new("a").attr("href", "https://example.com/?a=1&b=2"))
+        .layer(Element::new("script").text("alert(1)"))
+        .layer(rect(0.0, 0.0, 1.0, 1.0).child(Element::new("set").attr("attributeName", "href")));
+    assert_eq!(
+        svg.render(),
+        concat!(
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="10" height="10">"#,
+            r#"<a><text x="0" y="0">link</text></a>"#,
+            r#"<a href="https://example.com/?a=1&amp;b=2"/>"#,
+            r#"<rect x="0" y="0" width="1" height="1"></rect>"#,
+            r#"</svg>"#,
+        )
+    );
+}
+
+#[test]
+fn attributes_keep_their_order() {
+    let element = rect(0.0, 0.0, 1.0, 1.0)
+        .fill("red")
+        .attr("x", 5)
+        .fill("blue");
+    assert_eq!(
+        Svg::new(1.0, 1.0).layer(element).render(),
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="1" height="1"><rect x="5" y="0" width="1" height="1" fill="blue"/></svg>"#
+    );
+}
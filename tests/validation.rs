@@ -0,0 +1,237 @@
+use ownable_std::validation::{MAX_NAME_LENGTH, MetadataError, sanitize_svg};
+use ownable_std::{Metadata, Trait};
+
+const SAFE_SVG: &str = r##"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 10 10"><a href="#dot"><circle id="dot" cx="5" cy="5" r="4" fill="#f00"/></a><animateMotion dur="1s" path="M0,0 L1,1"/></svg>"##;
+
+fn image_data(svg: &str) -> Metadata {
+    Metadata {
+        image_data: Some(svg.into()),
+        ..Metadata::default()
+    }
+}
+
+#[test]
+fn safe_svg_is_unchanged() {
+    assert_eq!(sanitize_svg(SAFE_SVG), SAFE_SVG);
+    assert_eq!(image_data(SAFE_SVG).validate(), Ok(()));
+}
+
+#[test]
+fn removes_scripts_and_event_handlers() {
+    assert_eq!(
+        sanitize_svg(r#"<svg><script>alert(1)</script><g onload="alert(1)" fill="red"/></svg>"#),
+        r#"<svg><g fill="red"/></svg>"#
+    );
+    assert_eq!(
+        sanitize_svg(
+            "<svg><SCRIPT>alert(1)</Script><foreignObject><p>hi</p></foreignObject></svg>"
+        ),
+        "<svg></svg>"
+    );
+    assert_eq!(
+        sanitize_svg(r#"<svg><a xlink:href=" java	script:alert(1)">x</a></svg>"#),
+        "<svg><a>x</a></svg>"
+    );
+}
+
+#[test]
+fn decodes_entities_in_links() {
+    for href in [
+        "javascript&#58;alert(1)",
+        "&#106;avascript:alert(1)",
+        "&#x6A;avascript:alert(1)",
+        "&#0000106avascript:alert(1)",
+        "javascript&colon;alert(1)",
+        "java&Tab;script:alert(1)",
+        "java&#x09;script:alert(1)",
+    ] {
+        let svg = format!(r#"<svg><a href="{}">x</a></svg>"#, href);
+        assert_eq!(sanitize_svg(&svg), "<svg><a>x</a></svg>", "{}", href);
+        assert_eq!(
+            image_data(&svg).validate(),
+            Err(vec![MetadataError::UnsafeImageData])
+        );
+    }
+
+    // entities that don't spell out javascript: are kept as they are
+    let svg = r##"<svg><a href="#a&amp;b">x</a></svg>"##;
+    assert_eq!(sanitize_svg(svg), svg);
+}
+
+#[test]
+fn removes_animation_elements() {
+    assert_eq!(
+        sanitize_svg(
+            r#"<svg><a><animate attributeName="href" to="javascript:alert(1)"/>x</a></svg>"#
+        ),
+        "<svg><a>x</a></svg>"
+    );
+    assert_eq!(
+        sanitize_svg(r#"<svg><set attributeName="onload" to="alert(1)"></set></svg>"#),
+        "<svg></svg>"
+    );
+    assert_eq!(
+        sanitize_svg(
+            r#"<svg><g><animateTransform attributeName="transform" type="rotate"/></g></svg>"#
+        ),
+        "<svg><g></g></svg>"
+    );
+}
+
+#[test]
+fn removes_embedded_documents() {
+    assert_eq!(
+        sanitize_svg(
+            r#"<svg></svg><iframe srcdoc="&lt;script&gt;alert(1)&lt;/script&gt;"></iframe>"#
+        ),
+        "<svg></svg>"
+    );
+    assert_eq!(
+        sanitize_svg(r#"<iframe src="javascript:alert(1)"></iframe>"#),
+        ""
+    );
+    assert_eq!(
+        sanitize_svg(
+            r#"<svg><embed src="x.swf"/><object data="x.html"></object><style>@import "x.css";</style></svg>"#
+        ),
+        "<svg></svg>"
+    );
+}
+
+#[test]
+fn removes_javascript_urls() {
+    for attr in ["src", "data", "action", "formaction", "xlink:href"] {
+        let svg = format!(r#"<svg><image {}="JavaScript:alert(1)"/></svg>"#, attr);
+        assert_eq!(sanitize_svg(&svg), "<svg><image/></svg>", "{}", attr);
+        assert_eq!(
+            image_data(&svg).validate(),
+            Err(vec![MetadataError::UnsafeImageData])
+        );
+    }
+    assert_eq!(
+        sanitize_svg(r#"<svg><g srcdoc="hi"/></svg>"#),
+        "<svg><g/></svg>"
+    );
+
+    let svg = r#"<svg><image src="https://example.com/a.png"/></svg>"#;
+    assert_eq!(sanitize_svg(svg), svg);
+}
+
+#[test]
+fn drops_comments_and_processing_instructions() {
+    assert_eq!(
+        sanitize_svg("<svg><?x ><script>alert(1)</script>?></svg>"),
+        "<svg></svg>"
+    );
+    assert_eq!(
+        sanitize_svg("<svg><!-- --!><script>alert(1)</script> --></svg>"),
+        "<svg></svg>"
+    );
+    assert_eq!(
+        sanitize_svg("<?xml version=\"1.0\"?><!DOCTYPE svg><svg><!-- a comment --></svg>"),
+        "<svg></svg>"
+    );
+    // unterminated
+    assert_eq!(sanitize_svg("<svg><!-- <script>alert(1)</script>"), "<svg>");
+
+    let svg = "<svg><?x ><script>alert(1)</script>?></svg>";
+    assert_eq!(
+        image_data(svg).validate(),
+        Err(vec![MetadataError::UnsafeImageData])
+    );
+}
+
+#[test]
+fn escapes_cdata() {
+    assert_eq!(
+        sanitize_svg("<svg><text><![CDATA[a > b & c]]></text></svg>"),
+        "<svg><text>a &gt; b &amp; c</text></svg>"
+    );
+    assert_eq!(
+        sanitize_svg("<![CDATA[><script>alert(1)</script>]]><svg/>"),
+        "&gt;&lt;script&gt;alert(1)&lt;/script&gt;<svg/>"
+    );
+}
+
+#[test]
+fn sanitize_metadata() {
+    let mut metadata = image_data(r#"<svg onload="alert(1)"/>"#);
+    metadata.sanitize();
+    assert_eq!(metadata.image_data.as_deref(), Some("<svg/>"));
+    assert_eq!(metadata.validate(), Ok(()));
+}
+
+#[test]
+fn validate_urls() {
+    let metadata = Metadata {
+        image: Some("ipfs://QmImage".into()),
+        animation_url: Some("data:text/html,<p>hi</p>".into()),
+        external_url: Some("https://example.com/ownable".into()),
+        youtube_url: Some("https://youtube.com/watch?v=1".into()),
+        ..Metadata::default()
+    };
+    assert_eq!(metadata.validate(), Ok(()));
+
+    let metadata = Metadata {
+        image: Some("javascript:alert(1)".into()),
+        animation_url: Some("https://".into()),
+        external_url: Some("data:text/html,<p>hi</p>".into()),
+        youtube_url: Some("no scheme".into()),
+        ..Metadata::default()
+    };
+    assert_eq!(
+        metadata.validate(),
+        Err(vec![
+            MetadataError::InvalidUrlScheme {
+                field: "image",
+                value: "javascript:alert(1)".into()
+            },
+            MetadataError::InvalidUrl {
+                field: "animation_url",
+                value: "https://".into()
+            },
+            MetadataError::InvalidUrlScheme {
+                field: "external_url",
+                value: "data:text/html,<p>hi</p>".into()
+            },
+            MetadataError::InvalidUrl {
+                field: "youtube_url",
+                value: "no scheme".into()
+            },
+        ])
+    );
+}
+
+#[test]
+fn validate_lengths_and_colors() {
+    let metadata = Metadata {
+        name: Some("x".repeat(MAX_NAME_LENGTH + 1)),
+        background_color: Some("#ff0000".into()),
+        attributes: Some(vec![Trait::string("t".repeat(257), "v")]),
+        ..Metadata::default()
+    };
+    assert_eq!(
+        metadata.validate(),
+        Err(vec![
+            MetadataError::TooLong {
+                field: "name",
+                len: MAX_NAME_LENGTH + 1,
+                max: MAX_NAME_LENGTH
+            },
+            MetadataError::InvalidBackgroundColor {
+                value: "#ff0000".into()
+            },
+            MetadataError::TooLong {
+                field: "trait_type",
+                len: 257,
+                max: 256
+            },
+        ])
+    );
+
+    let metadata = Metadata {
+        background_color: Some("3C9EE7".into()),
+        ..Metadata::default()
+    };
+    assert_eq!(metadata.validate(), Ok(()));
+}
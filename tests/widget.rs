@@ -0,0 +1,99 @@
+mod common;
+
+use common::ownable_info;
+use cosmwasm_std::testing::{mock_dependencies, mock_env, mock_info};
+use cosmwasm_std::{Addr, to_json_string};
+use ownable_std::Metadata;
+use ownable_std::consumable::try_consume;
+use ownable_std::lock::try_lock;
+use ownable_std::ownership::init_ownership;
+use ownable_std::widget::{WidgetLayer, WidgetState, query_widget_state};
+use serde_json::json;
+
+fn metadata() -> Metadata {
+    Metadata {
+        name: Some("Potion".into()),
+        description: Some("Heals 10 hp".into()),
+        image: Some("potion.png".into()),
+        background_color: Some("ff0000".into()),
+        // not shown by the widget
+        external_url: Some("https://example.com".into()),
+        ..Metadata::default()
+    }
+}
+
+#[test]
+fn widget_state_json() {
+    let widget_state = WidgetState::new(&metadata(), &ownable_info())
+        .with_color("#00ff00")
+        .with_layer(WidgetLayer {
+            name: "bottle".into(),
+            image: Some("bottle.png".into()),
+            color: None,
+        })
+        .with_extra("charges", &3)
+        .unwrap()
+        .with_extra("effects", &["heal", "glow"])
+        .unwrap();
+
+    assert_eq!(
+        serde_json::to_value(&widget_state).unwrap(),
+        json!({
+            "name": "Potion",
+            "description": "Heals 10 hp",
+            "image": "potion.png",
+            "image_data": null,
+            "background_color": "ff0000",
+            "color": "#00ff00",
+            "layers": [{ "name": "bottle", "image": "bottle.png", "color": null }],
+            "owner": "owner",
+            "issuer": "issuer",
+            "ownable_type": "ticket",
+            "consumed": false,
+            "locked": false,
+            "extras": { "charges": 3, "effects": ["heal", "glow"] },
+        })
+    );
+}
+
+#[test]
+fn empty_layers_and_extras_are_omitted() {
+    let widget_state = WidgetState::from_metadata(&metadata());
+    let json = to_json_string(&widget_state).unwrap();
+    assert!(!json.contains("layers"));
+    assert!(!json.contains("extras"));
+    assert!(json.contains(r#""owner":null"#));
+
+    // and default to empty when deserializing
+    let parsed: WidgetState = serde_json::from_str(&json).unwrap();
+    assert_eq!(parsed, widget_state);
+}
+
+#[test]
+fn query_widget_state_flags() {
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+
+    let widget_state = query_widget_state(deps.as_ref(), &metadata()).unwrap();
+    assert_eq!(widget_state.owner, Some(Addr::unchecked("owner")));
+    assert!(!widget_state.locked);
+    assert!(!widget_state.consumed);
+
+    try_consume(deps.as_mut(), mock_env(), mock_info("owner", &[])).unwrap();
+    let widget_state = query_widget_state(deps.as_ref(), &metadata()).unwrap();
+    assert!(!widget_state.locked);
+    assert!(widget_state.consumed);
+
+    let mut deps = mock_dependencies();
+    init_ownership(deps.as_mut().storage, &ownable_info(), None).unwrap();
+    try_lock(deps.as_mut(), mock_info("owner", &[])).unwrap();
+    let widget_state = query_widget_state(deps.as_ref(), &metadata()).unwrap();
+    assert!(widget_state.locked);
+    assert!(!widget_state.consumed);
+}
+
+#[test]
+fn query_widget_state_requires_ownership() {
+    let deps = mock_dependencies();
+    assert!(query_widget_state(deps.as_ref(), &metadata()).is_err());
+}